@@ -0,0 +1,76 @@
+//! Scaffolding of a package nested 5 levels deep into an output directory, in
+//! memory so the disk does not drown the time spent building the paths.
+//!
+//! Run with `cargo bench`. Besides the criterion timings, each case prints the
+//! heap allocations of one run, counted by the global allocator below.
+use criterion::{Criterion, criterion_group, criterion_main};
+use python_skeleton::SkeletonOptions;
+use python_skeleton::dir_builder::make_dirs_in;
+use python_skeleton::file_system::MemoryFileSystem;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The system allocator, counting every allocation.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Prints the allocations of one call of `routine`, then benches it.
+fn bench(c: &mut Criterion, name: &str, mut routine: impl FnMut()) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    routine();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("{name}: {allocations} allocations");
+    c.bench_function(name, |b| b.iter(&mut routine));
+}
+
+fn nested_packages(c: &mut Criterion) {
+    let options = SkeletonOptions::new("Bench-Project", "acme.client.http.api.models")
+        .output_dir("projects/python")
+        .force(true);
+
+    bench(c, "make_dirs (5 levels)", || {
+        let fs = MemoryFileSystem::new();
+        black_box(
+            make_dirs_in(
+                &fs,
+                Path::new("projects/python"),
+                black_box(&options),
+                &mut io::sink(),
+            )
+            .unwrap(),
+        );
+    });
+    bench(c, "preview (5 levels)", || {
+        black_box(black_box(&options).preview().unwrap());
+    });
+    bench(c, "build_in (5 levels)", || {
+        let fs = MemoryFileSystem::new();
+        black_box(black_box(options.clone()).build_in(&fs).unwrap());
+    });
+}
+
+criterion_group!(benches, nested_packages);
+criterion_main!(benches);
@@ -11,83 +11,195 @@
 //! ├── config/
 //! ├── files/
 //! ├── notebooks/
-//! ├── test/
+//! ├── test/ (or tests/)
 //! ├── src/
 //! │   └── <package_name>/
-//! └── docs/ (optional)
+//! ├── docs/ (optional)
+//! └── .github/workflows/ (optional)
 //! ```
-use std::fs::DirBuilder;
+//!
+//! A dotted package name such as `acme.client.http` is expanded into nested
+//! directories: `src/acme/client/http/`. With [`crate::Layout::FlatLayout`] there is no
+//! `src/` and the package sits directly under `<root_name>/`.
+//!
+//! With [`SkeletonOptions::use_current_as_root`] the provided path is the root
+//! itself: `<root_name>/` is not created, and the path must be an empty directory.
+#[cfg(doc)]
+use crate::Layout;
+use crate::SkeletonOptions;
+use crate::file_system::{FileSystem, OsFileSystem, PathResult, with_path};
+use log::debug;
 use std::io;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 /// Generates the list of directory paths required for the project structure.
 ///
-/// This is an internal helper function used by [`make_dirs`].
-fn get_dirs(root_name: &str, docs: bool, package_name: &str) -> Vec<String> {
-    let mut dirs = Vec::from([
-        root_name.to_string(),
-        format!("{root_name}/config"),
-        format!("{root_name}/files"),
-        format!("{root_name}/notebooks"),
-        format!("{root_name}/test"),
-        format!("{root_name}/src"),
-        format!("{root_name}/src/{package_name}"),
+/// This is an internal helper function used by [`make_dirs`] and by dry runs.
+pub(crate) fn get_dirs(options: &SkeletonOptions) -> Vec<PathBuf> {
+    let root = PathBuf::from(&options.project_name);
+    // The fixed directories, `src`, `docs` and the two of the CI, then one per
+    // package segment.
+    let mut dirs = Vec::with_capacity(9 + options.package_name.split('.').count());
+    dirs.extend([
+        root.clone(),
+        root.join("config"),
+        root.join("files"),
+        root.join("notebooks"),
+        root.join(options.test_dir_name()),
     ]);
-    if docs {
-        dirs.push(format!("{root_name}/docs"));
+    let mut package_dir = options.source_dir();
+    if package_dir != root {
+        dirs.push(package_dir.clone());
+    }
+    // One directory per segment of a dotted package, parents first.
+    for segment in options.package_name.split('.') {
+        package_dir.push(segment);
+        dirs.push(package_dir.clone());
+    }
+    if options.include_doc_dir {
+        dirs.push(root.join("docs"));
     }
-    dirs
+    if options.include_ci {
+        dirs.push(root.join(".github"));
+        dirs.push(root.join(".github").join("workflows"));
+    }
+    // The parents of the extra files, parents first, in one buffer popped back to
+    // the root after each file.
+    let mut dir = root.clone();
+    for (path, _) in &options.extra_files {
+        let parent = path.parent().unwrap_or(Path::new(""));
+        for component in parent.components() {
+            dir.push(component);
+            if !dirs.contains(&dir) {
+                dirs.push(dir.clone());
+            }
+        }
+        for _ in parent.components() {
+            dir.pop();
+        }
+    }
+    dirs.into_iter()
+        .map(|dir| options.relative_to_output(dir))
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .collect()
 }
 
 /// Creates a standardized python directory tree on the file system.
 ///
 /// This function iterates through the required project directories and creates them
-/// using [`DirBuilder`].
+/// on the disk with [`OsFileSystem`], writing one line to `progress` as each one is
+/// created, e.g. [`io::stdout`] to show it or [`io::sink`] to ignore it. Missing
+/// intermediate parents are created too, and directories that already exist are
+/// left as they are, so running it twice over the same tree succeeds.
+///
+/// Returns the path of every directory it actually created, in creation order:
+/// the project root comes first and parents always precede their children, so
+/// reversing the list is a safe removal order. Pre-existing directories are not
+/// listed.
 ///
 /// # Arguments
 ///
-/// * `parent_dir` - The base path where the project root will be created.
-/// * `root_name` - The name of the project root directory.
-/// * `docs` - A boolean flag; if true, a `docs/` folder will be created.
-/// * `package_name` - The name of the package inside the `src/` directory.
-/// * `verbose` - A boolean flag, if true, print a message of current direcoty build
+/// * `parent_dir` - The base path where the project root will be created. Any
+///   [`Path`] works; a `&PathBuf` deref-coerces to it.
+/// * `options` - The project and package names, the [`crate::Layout`] and whether to
+///   create a `docs/` folder. Names are used as given, they are only validated by
+///   [`SkeletonOptions::build`]. Dotted package names (`acme.client`) create one
+///   nested directory per segment.
+/// * `progress` - Where the created directories are reported. Failing to write it
+///   does not fail the build.
 ///
 /// # Errors
 ///
-/// This function will return an [`io::Error`] if:
+/// This function will return an [`io::Error`], naming the failing directory, if:
 /// * The program lacks permissions to create directories in the `parent_dir`.
-/// * A file already exists at one of the paths where a directory is being created.
+/// * A file (not a directory) already exists at one of the paths.
+/// * [`SkeletonOptions::use_current_as_root`] is set and `parent_dir` does not
+///   exist ([`io::ErrorKind::NotFound`]) or is not empty
+///   ([`io::ErrorKind::DirectoryNotEmpty`]).
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use std::path::PathBuf;
+/// use python_skeleton::SkeletonOptions;
 /// use python_skeleton::dir_builder::make_dirs;
 ///
 /// fn main() -> std::io::Result<()> {
 ///     let path = PathBuf::from("./projects");
-///     make_dirs(&path, "my_new_project", true, "my_package", false)?;
+///     let options = SkeletonOptions::new("My-New-Project", "my_package").docs(true);
+///     let created = make_dirs(&path, &options, &mut std::io::stdout())?;
+///     println!("Created {} directories", created.len());
 ///     Ok(())
 /// }
 /// ```
 pub fn make_dirs(
     parent_dir: &Path,
-    root_name: &str,
-    docs: bool,
-    package_name: &str,
-    verbose: bool,
-) -> io::Result<()> {
-    let dirs_names = get_dirs(root_name, docs, package_name);
-    let dir_builder = DirBuilder::new();
+    options: &SkeletonOptions,
+    progress: &mut dyn Write,
+) -> io::Result<Vec<PathBuf>> {
+    make_dirs_in(&OsFileSystem, parent_dir, options, progress)
+}
+
+/// Same as [`make_dirs`], creating the directories in `fs` instead of the disk.
+///
+/// # Errors
+///
+/// Returns the first [`io::Error`] of `fs`.
+pub fn make_dirs_in(
+    fs: &impl FileSystem,
+    parent_dir: &Path,
+    options: &SkeletonOptions,
+    progress: &mut dyn Write,
+) -> io::Result<Vec<PathBuf>> {
+    if options.use_current_as_root {
+        if !fs.is_dir(parent_dir) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} does not exist", parent_dir.display()),
+            ));
+        }
+        if !fs.is_empty_dir(parent_dir) {
+            return Err(io::Error::new(
+                io::ErrorKind::DirectoryNotEmpty,
+                format!("{} is not empty", parent_dir.display()),
+            ));
+        }
+    }
+    let mut created = Vec::new();
+    create_dirs(fs, parent_dir, &get_dirs(options), &mut created, progress).map_err(with_path)?;
+    Ok(created)
+}
+
+/// Does the work of [`make_dirs_in`] for the `dirs_names` of [`get_dirs`], plus
+/// any other the caller needs, pushing each directory into `created` as soon as
+/// it exists, so the caller still knows what to roll back when it fails midway,
+/// and reporting it to `progress`. The error comes with the directory that could
+/// not be created.
+pub(crate) fn create_dirs(
+    fs: &impl FileSystem,
+    parent_dir: &Path,
+    dirs_names: &[PathBuf],
+    created: &mut Vec<PathBuf>,
+    progress: &mut dyn Write,
+) -> PathResult {
+    // One buffer for every directory: the names are pushed onto `parent_dir` and
+    // popped back off, only the created directories are copied.
+    let mut path = parent_dir.to_path_buf();
     for dir_name in dirs_names {
-        // Clone `parent_dir` to not edit the original path
-        let mut parent_copy = parent_dir.to_path_buf();
-        parent_copy.push(&dir_name);
-        if verbose {
-            println!("Creating directory: {}", parent_copy.display());
+        path.push(dir_name);
+        if fs.is_dir(&path) {
+            debug!("Directory already exists: {}", path.display());
+        } else {
+            if let Err(error) = fs.create_dir(&path) {
+                return Err((path, error));
+            }
+            let _ = writeln!(progress, "Created directory {}", path.display());
+            created.push(path.clone());
+        }
+        for _ in dir_name.components() {
+            path.pop();
         }
-        let result = dir_builder.create(parent_copy);
-        result?;
     }
     Ok(())
 }
@@ -95,14 +207,116 @@ pub fn make_dirs(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env::current_dir;
-    use std::fs::remove_dir_all;
+    use crate::Layout;
+    use crate::file_system::MemoryFileSystem;
+    use tempfile::tempdir;
 
     #[test]
     fn test_make_directories() {
-        let mut dir = current_dir().unwrap();
-        assert!(make_dirs(&mut dir, "test-build", false, "test_build", false).is_ok());
-        dir.push("test-build");
-        let _ = remove_dir_all(dir);
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let created = make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        let expected: Vec<_> = get_dirs(&options)
+            .iter()
+            .map(|name| dir.path().join(name))
+            .collect();
+        assert_eq!(created, expected);
+    }
+
+    #[test]
+    fn test_make_directories_progress() {
+        let fs = MemoryFileSystem::new();
+        let mut progress = Vec::new();
+        let options = SkeletonOptions::new("test-build", "test_build");
+        make_dirs_in(&fs, Path::new("out"), &options, &mut progress).unwrap();
+        let progress = String::from_utf8(progress).unwrap();
+        assert!(progress.starts_with("Created directory out/test-build\n"));
+        assert!(progress.contains("Created directory out/test-build/src/test_build\n"));
+    }
+
+    #[test]
+    fn test_make_directories_twice() {
+        let dir = tempdir().unwrap();
+        let parent = dir.path().join("not/yet/there");
+        let options = SkeletonOptions::new("test-build", "test_build").docs(true);
+        assert!(make_dirs(&parent, &options, &mut io::sink()).is_ok());
+
+        let created = make_dirs(&parent, &options, &mut io::sink()).unwrap();
+        assert!(created.is_empty());
+        assert!(parent.join("test-build/docs").is_dir());
+    }
+
+    #[test]
+    fn test_current_dir_as_root() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").use_current_as_root(true);
+        let created = make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        assert!(!created.contains(&dir.path().to_path_buf()));
+        assert!(dir.path().join("src/test_build").is_dir());
+        assert!(!dir.path().join("test-build").exists());
+
+        let error = make_dirs(dir.path(), &options, &mut io::sink()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::DirectoryNotEmpty);
+        let error = make_dirs(&dir.path().join("missing"), &options, &mut io::sink()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_nested_package_directories() {
+        let dir = tempdir().unwrap();
+        make_dirs(
+            dir.path(),
+            &SkeletonOptions::new("test-build", "acme.client"),
+            &mut io::sink(),
+        )
+        .unwrap();
+        assert!(dir.path().join("test-build/src/acme/client").is_dir());
+
+        let options = SkeletonOptions::new("test-build", "acme.client.http");
+        let created = make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        assert_eq!(
+            created,
+            vec![dir.path().join("test-build/src/acme/client/http")]
+        );
+    }
+
+    #[test]
+    fn test_platform_separator() {
+        let options = SkeletonOptions::new("test-build", "acme.client");
+        let expected = ["test-build", "src", "acme", "client"].join(std::path::MAIN_SEPARATOR_STR);
+        let dirs = get_dirs(&options);
+        assert!(dirs.iter().any(|dir| dir.to_string_lossy() == expected));
+    }
+
+    #[test]
+    fn test_flat_layout_directories() {
+        let options = SkeletonOptions::new("test-build", "acme.client").layout(Layout::FlatLayout);
+        let dirs = get_dirs(&options);
+        assert!(dirs.contains(&PathBuf::from("test-build/acme/client")));
+        assert!(!dirs.iter().any(|dir| dir.ends_with("src")));
+    }
+
+    #[test]
+    fn test_tests_directory() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        assert!(get_dirs(&options).contains(&PathBuf::from("test-build/test")));
+
+        let dirs = get_dirs(&options.tests_dir(true));
+        assert!(dirs.contains(&PathBuf::from("test-build/tests")));
+        assert!(!dirs.contains(&PathBuf::from("test-build/test")));
+    }
+
+    #[test]
+    fn test_make_directories_in_memory() {
+        let fs = MemoryFileSystem::new();
+        let options = SkeletonOptions::new("test-build", "acme.client");
+        let created = make_dirs_in(&fs, Path::new("out"), &options, &mut io::sink()).unwrap();
+        assert_eq!(created[0], Path::new("out/test-build"));
+        assert!(fs.is_dir(Path::new("out/test-build/src/acme/client")));
+        assert!(
+            make_dirs_in(&fs, Path::new("out"), &options, &mut io::sink())
+                .unwrap()
+                .is_empty()
+        );
     }
 }
@@ -0,0 +1,259 @@
+//! Filesystem Abstraction
+//!
+//! The builders never call [`std::fs`] directly: they go through a [`FileSystem`],
+//! so the same scaffold can be written to disk with [`OsFileSystem`] or kept in
+//! memory with [`MemoryFileSystem`], e.g. to snapshot-test the output or to
+//! stream it into an archive.
+//!
+//! Every method takes `&self` because files are written by parallel threads, so
+//! implementations must be [`Sync`] and handle their own locking.
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{DirBuilder, File, remove_dir, remove_file, rename};
+use std::io;
+use std::io::prelude::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Mutex, MutexGuard};
+
+/// A failed filesystem operation with the path it failed on, as returned by the
+/// builders before [`crate::BuildError::PathError`] or [`with_path`] wrap it.
+pub(crate) type PathResult = Result<(), (PathBuf, io::Error)>;
+
+/// Folds `path` into the message of `error`, keeping its kind, for the public
+/// builders which return a plain [`io::Error`].
+pub(crate) fn with_path((path, error): (PathBuf, io::Error)) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+}
+
+/// Where a skeleton is written.
+pub trait FileSystem: Sync {
+    /// Creates `path` and any missing parent. Succeeds if it already exists.
+    fn create_dir(&self, path: &Path) -> io::Result<()>;
+    /// Writes `content` to `path`, replacing any existing file. The parent
+    /// directory must exist.
+    fn write_file(&self, path: &Path, content: &[u8]) -> io::Result<()>;
+    /// Whether a file or directory exists at `path`.
+    fn exists(&self, path: &Path) -> bool;
+    /// Whether a directory exists at `path`.
+    fn is_dir(&self, path: &Path) -> bool;
+    /// Whether a directory exists at `path` and holds nothing.
+    fn is_empty_dir(&self, path: &Path) -> bool;
+    /// Removes the file at `path`.
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    /// Removes the directory at `path`, which must be empty.
+    fn remove_dir(&self, path: &Path) -> io::Result<()>;
+}
+
+/// The real filesystem of the operating system.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        DirBuilder::new().recursive(true).create(path)
+    }
+
+    /// Writes `content` to a temporary file next to `path`, then renames it into
+    /// place, so `path` is never seen half written: it is either missing, the old
+    /// file or the whole new one. The temporary file is removed on failure.
+    fn write_file(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let tmp_path = path.with_file_name(format!(".{file_name}.{}.tmp", process::id()));
+        let result = File::create(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(content)?;
+                file.sync_all()
+            })
+            .and_then(|()| rename(&tmp_path, path));
+        if result.is_err() {
+            let _ = remove_file(&tmp_path);
+        }
+        result
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn is_empty_dir(&self, path: &Path) -> bool {
+        path.read_dir()
+            .is_ok_and(|mut entries| entries.next().is_none())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        remove_file(path)
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        remove_dir(path)
+    }
+}
+
+/// A filesystem kept in memory, keyed by the paths the builders use.
+///
+/// Paths are stored as given, so building with a relative output directory
+/// (e.g. `""`) gives keys such as `My-Project/README.md`. The empty path is the
+/// root of the filesystem and always exists.
+///
+/// # Examples
+///
+/// ```
+/// use python_skeleton::SkeletonOptions;
+/// use python_skeleton::file_system::MemoryFileSystem;
+/// use std::path::Path;
+///
+/// let fs = MemoryFileSystem::new();
+/// SkeletonOptions::new("My-Project", "my_package")
+///     .output_dir("")
+///     .build_in(&fs)
+///     .unwrap();
+/// let readme = fs.file(Path::new("My-Project/README.md")).unwrap();
+/// assert!(readme.starts_with(b"# My-Project"));
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    dirs: Mutex<BTreeSet<PathBuf>>,
+    files: Mutex<HashMap<PathBuf, Vec<u8>>>,
+}
+
+impl MemoryFileSystem {
+    /// An empty filesystem.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The content of the file at `path`, if any.
+    pub fn file(&self, path: &Path) -> Option<Vec<u8>> {
+        self.files().get(path).cloned()
+    }
+
+    /// Every directory, sorted.
+    pub fn dirs(&self) -> Vec<PathBuf> {
+        lock(&self.dirs).iter().cloned().collect()
+    }
+
+    /// Consumes the filesystem, returning the content of every file.
+    pub fn into_files(self) -> HashMap<PathBuf, Vec<u8>> {
+        self.files
+            .into_inner()
+            .unwrap_or_else(|poison| poison.into_inner())
+    }
+
+    fn files(&self) -> MutexGuard<'_, HashMap<PathBuf, Vec<u8>>> {
+        lock(&self.files)
+    }
+}
+
+/// Locks `mutex`, ignoring a poison: a panicking writer can not leave a map
+/// half updated.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poison| poison.into_inner())
+}
+
+/// The error of a missing path, like the OS gives.
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn create_dir(&self, path: &Path) -> io::Result<()> {
+        if self.files().contains_key(path) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} is a file", path.display()),
+            ));
+        }
+        let mut dirs = lock(&self.dirs);
+        for ancestor in path.ancestors() {
+            if !ancestor.as_os_str().is_empty() {
+                dirs.insert(ancestor.to_path_buf());
+            }
+        }
+        Ok(())
+    }
+
+    fn write_file(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        let parent = path.parent().unwrap_or(Path::new(""));
+        if !self.is_dir(parent) {
+            return Err(not_found(parent));
+        }
+        self.files().insert(path.to_path_buf(), content.to_vec());
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.is_dir(path) || self.files().contains_key(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.as_os_str().is_empty() || lock(&self.dirs).contains(path)
+    }
+
+    fn is_empty_dir(&self, path: &Path) -> bool {
+        let in_dir = |entry: &Path| entry != path && entry.starts_with(path);
+        self.is_dir(path)
+            && !lock(&self.dirs).iter().any(|dir| in_dir(dir))
+            && !self.files().keys().any(|file| in_dir(file))
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        match self.files().remove(path) {
+            Some(_) => Ok(()),
+            None => Err(not_found(path)),
+        }
+    }
+
+    fn remove_dir(&self, path: &Path) -> io::Result<()> {
+        let in_dir = |entry: &Path| entry != path && entry.starts_with(path);
+        if lock(&self.dirs).iter().any(|dir| in_dir(dir))
+            || self.files().keys().any(|file| in_dir(file))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::DirectoryNotEmpty,
+                format!("{} is not empty", path.display()),
+            ));
+        }
+        match lock(&self.dirs).remove(path) {
+            true => Ok(()),
+            false => Err(not_found(path)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_file_system() {
+        let fs = MemoryFileSystem::new();
+        assert!(fs.is_empty_dir(Path::new("")));
+        fs.create_dir(Path::new("root/src/pkg")).unwrap();
+        assert!(fs.is_dir(Path::new("root/src")));
+        fs.write_file(Path::new("root/src/pkg/a.py"), b"a").unwrap();
+        assert_eq!(fs.file(Path::new("root/src/pkg/a.py")), Some(b"a".to_vec()));
+
+        let error = fs.write_file(Path::new("root/docs/a.md"), b"").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        let error = fs.remove_dir(Path::new("root/src/pkg")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::DirectoryNotEmpty);
+        assert!(!fs.is_empty_dir(Path::new("root/src")));
+        assert!(!fs.is_empty_dir(Path::new("root/docs")));
+
+        fs.remove_file(Path::new("root/src/pkg/a.py")).unwrap();
+        fs.remove_dir(Path::new("root/src/pkg")).unwrap();
+        assert!(fs.is_empty_dir(Path::new("root/src")));
+        assert_eq!(
+            fs.dirs(),
+            vec![PathBuf::from("root"), PathBuf::from("root/src")]
+        );
+        assert!(fs.into_files().is_empty());
+    }
+}
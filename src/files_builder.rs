@@ -9,80 +9,845 @@
 //! |-----------|-------------|
 //! | `README.md` | Basic project documentation. |
 //! | `pyproject.toml` | Build system requirements and metadata. |
-//! | `.gitignore` | Standard patterns for Python and IDEs. |
+//! | `setup.cfg`, `setup.py` | The same metadata for setuptools, only with [`crate::PackagingStyle::Legacy`]. |
+//! | `requirements.txt` | The runtime dependencies, one per line, only when asked for. |
+//! | `.gitignore` | Standard patterns for Python and IDEs, with a [`crate::GitignorePreset`]. |
 //! | `src/<package>/__init__.py` | Init file for python package. |
+//! | `src/<parent>/__init__.py` | Init file for each parent of a dotted package, unless a namespace. |
 //! | `src/<package>/main.py` | The main entry point for the application. |
-//! | `src/<package>/env.py` | Environment loading boilerplate.
-//! | `src/<package>/db.py` | Database connection boilerplate. |
-//! | `config/DEV.yaml` | Development environment configuration. |
-//! | `test/sample_test.py` | Placeholder for unittest. |
+//! | `src/<package>/__main__.py` | Runs `main()` for `python -m <package>`. |
+//! | `src/<package>/env.py` | Environment loading boilerplate (optional). |
+//! | `src/<package>/logging_config.py` | structlog setup and `get_logger`, only when asked for. |
+//! | `src/<package>/db.py` | Connection to the chosen [`crate::Database`], if any. |
+//! | `mkdocs.yml`, `docs/index.md` | Starter docs, only with docs and [`crate::DocEngine::MkDocs`]. |
+//! | `docs/conf.py`, `docs/index.rst` | Starter docs, only with docs and [`crate::DocEngine::Sphinx`]. |
+//! | `config/<ENV>.yaml` | Configuration of each environment, `DEV` by default. |
+//! | `test/sample_test.py` | Placeholder for unittest (`tests/` if chosen). |
+//! | `.python-version` | The pinned interpreter for pyenv and uv, only when asked for. |
+//! | `VERSION` | The version of the project, only when asked for. |
+//! | `test/conftest.py` | Shared pytest fixtures, only when asked for. |
+//! | `src/<package>/py.typed` | PEP 561 marker for type hints, only when asked for. |
+//! | `.github/workflows/ci.yml` | GitHub Actions workflow, only when asked for. |
+//! | `Dockerfile` | Multi-stage image build, only when asked for. |
+//! | `.dockerignore` | Files kept out of the image build context. |
+//! | `.pre-commit-config.yaml` | Ruff hooks for `pre-commit`, only when asked for. |
+//! | `.editorconfig` | Indentation and newline rules, only when asked for. |
+//! | `CONTRIBUTING.md` | How to report issues and open pull requests, only when asked for. |
+//! | `CODE_OF_CONDUCT.md` | Contributor Covenant based code of conduct, with the above. |
+//! | `tox.ini` or `noxfile.py` | Tests for every supported Python version, with a [`crate::TestRunner`]. |
+//! | `Makefile` | `install`, `test`, `lint`, `format` and `run` tasks, only when asked for. |
+//! | `CHANGELOG.md` | Keep a Changelog entries from the initial version, only when asked for. |
+//! | `SECURITY.md` | How to report a vulnerability privately, only when asked for. |
+//! | `LICENSE` | License text, only when a [`crate::License`] is chosen. |
+//!
+//! With [`Layout::FlatLayout`] the package files drop the `src/` prefix.
 pub mod files_content;
 
-use std::fs::File;
+use crate::file_system::{FileSystem, OsFileSystem, PathResult, with_path};
+use crate::{
+    BuildBackend, BuildError, Database, DocEngine, Layout, OverwritePolicy, PackagingStyle,
+    SkeletonOptions, TestRunner,
+};
+use log::{debug, info};
+use std::error::Error;
+use std::fmt;
+use std::fs::{read_dir, read_to_string};
 use std::io;
-use std::io::prelude::Write;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Maps project file paths to their respective boilerplate content.
+/// Values substituted into the `{{name}}` placeholders of the templates.
 ///
-/// This internal function retrieves strings from [`files_content`] and performs
-/// necessary string replacements (like inserting the `package_name` into the TOML).
+/// Every field is available under its own name, e.g. `{{author}}`. Besides the
+/// metadata, `{{source_dir}}` and `{{test_dir}}` give the directories chosen by the
+/// [`Layout`] and [`SkeletonOptions::tests_dir`], relative to the project root.
 ///
-/// Returns a [`Vec`] of tuples containing `(file_path, file_content)`.
-fn get_files(root_name: &str, package_name: &str) -> Vec<(String, String)> {
-    Vec::from([
-        (
-            format!("{root_name}/README.md"),
-            files_content::SAMPLE_README.to_string(),
-        ),
-        (
-            format!("{root_name}/pyproject.toml"),
-            files_content::SAMPLE_PYPROJECT.replace("{}", package_name),
-        ),
-        (
-            format!("{root_name}/.gitignore"),
-            files_content::SAMPLE_GITIGNORE.to_string(),
+/// Fields ending in `_field` hold whole lines for optional settings (newline
+/// included), or nothing when the setting is off.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TemplateContext {
+    /// The project name, as used for the root directory.
+    pub project_name: String,
+    /// The package name, dotted for nested packages.
+    pub package_name: String,
+    /// The console command running the package, the lowercase project name.
+    pub command_name: String,
+    /// One line describing the project, used as the README tagline.
+    pub description: String,
+    /// The author of the project.
+    pub author: String,
+    /// The current year, for copyright notices.
+    pub year: String,
+    /// The current date, `YYYY-MM-DD`, for the first changelog entry.
+    pub date: String,
+    /// The initial version of the project.
+    pub version: String,
+    /// The address vulnerabilities are reported to, in `SECURITY.md`.
+    pub security_email: String,
+    /// The Python version targeted by the project, e.g. `3.14`.
+    pub python_version: String,
+    /// The same version as a tag, e.g. `py314`, for the ruff `target-version`.
+    pub python_tag: String,
+    /// The tox environments of every supported version, e.g. `py313, py314`.
+    pub tox_envs: String,
+    /// The quoted supported versions, e.g. `"3.13", "3.14"`, for nox.
+    pub nox_pythons: String,
+    /// The directory holding the top-level package (`src` or the package itself).
+    pub source_dir: String,
+    /// The unit tests directory (`test` or `tests`).
+    pub test_dir: String,
+    /// The quoted config file of every environment, e.g. `"DEV.yaml", "PROD.yaml"`,
+    /// searched by `env.py`.
+    pub config_names: String,
+    /// The `license = "<SPDX id>"` line of `pyproject.toml`.
+    pub license_field: String,
+    /// The entries of the `dependencies` array of `pyproject.toml`, one per line.
+    pub dependencies: String,
+    /// The same dependencies for `requirements.txt`, unquoted, each line ended.
+    pub requirements: String,
+    /// The entries of the `dev` dependency group of `pyproject.toml`, one per line.
+    pub dev_dependencies: String,
+    /// The `requires` and `build-backend` lines of the `[build-system]` table.
+    pub build_system: String,
+    /// The `[tool.<backend>]` table locating the package, for backends that need it.
+    pub backend_tool_field: String,
+    /// The `[tool.mypy]` table, and the `py.typed` package data for `setuptools`.
+    pub typing_field: String,
+    /// The `license = <SPDX id>` line of `setup.cfg`.
+    pub setup_license_field: String,
+    /// The same dependencies as `pyproject.toml` for `setup.cfg`, indented, each
+    /// line ended.
+    pub install_requires: String,
+    /// The `[options]` lines of `setup.cfg` telling setuptools where the packages are.
+    pub setup_packages_field: String,
+    /// The `[options.packages.find]` table of `setup.cfg`, and the `py.typed`
+    /// package data.
+    pub setup_find_field: String,
+    /// The import of `logging_config` in `__init__.py`, which sets up structlog.
+    pub logging_field: String,
+    /// The `.gitignore` patterns of the [`crate::GitignorePreset`], after the Python ones.
+    pub gitignore_patterns: String,
+}
+
+impl TemplateContext {
+    /// Collects the values of every placeholder from the build options.
+    pub(crate) fn from_options(options: &SkeletonOptions) -> Self {
+        let top_package = options.package_name.split('.').next().unwrap_or_default();
+        let (build_system, backend_tool_field) = build_backend(options, top_package);
+        let (setup_packages_field, setup_find_field) = setup_packages(options, top_package);
+        let (year, month, day) = current_date();
+        TemplateContext {
+            project_name: options.project_name.clone(),
+            package_name: options.package_name.clone(),
+            command_name: options.project_name.to_lowercase(),
+            description: options.description.clone(),
+            author: options.author.clone(),
+            year: year.to_string(),
+            date: format!("{year:04}-{month:02}-{day:02}"),
+            version: options.version.clone(),
+            security_email: options.security_email.clone(),
+            python_version: options.python_version.clone(),
+            python_tag: format!("py{}", options.python_version.replace('.', "")),
+            tox_envs: supported_versions(options)
+                .iter()
+                .map(|version| format!("py{}", version.replace('.', "")))
+                .collect::<Vec<_>>()
+                .join(", "),
+            nox_pythons: supported_versions(options)
+                .iter()
+                .map(|version| format!("\"{version}\""))
+                .collect::<Vec<_>>()
+                .join(", "),
+            source_dir: match options.layout {
+                Layout::SrcLayout => "src".to_string(),
+                Layout::FlatLayout => top_package.to_string(),
+            },
+            test_dir: options.test_dir_name().to_string(),
+            config_names: options
+                .environments
+                .iter()
+                .map(|environment| format!("\"{environment}.yaml\""))
+                .collect::<Vec<_>>()
+                .join(", "),
+            license_field: match options.license.spdx_id() {
+                Some(id) => format!("license = \"{id}\"\n"),
+                None => String::new(),
+            },
+            dependencies: dependencies(options)
+                .iter()
+                .map(|dependency| format!("    \"{}\"", toml_escape(dependency)))
+                .collect::<Vec<_>>()
+                .join(",\n"),
+            requirements: dependencies(options)
+                .iter()
+                .map(|dependency| format!("{dependency}\n"))
+                .collect(),
+            dev_dependencies: dev_dependencies(options)
+                .iter()
+                .map(|dependency| format!("    \"{}\",", toml_escape(dependency)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            build_system,
+            backend_tool_field,
+            typing_field: typing_tables(options),
+            setup_license_field: match options.license.spdx_id() {
+                Some(id) => format!("license = {id}\n"),
+                None => String::new(),
+            },
+            install_requires: dependencies(options)
+                .iter()
+                .map(|dependency| format!("    {dependency}\n"))
+                .collect(),
+            setup_packages_field,
+            setup_find_field,
+            logging_field: if options.include_logging {
+                "from .logging_config import get_logger as get_logger\n\n".to_string()
+            } else {
+                String::new()
+            },
+            gitignore_patterns: files_content::gitignore_patterns(options.gitignore_preset)
+                .to_string(),
+        }
+    }
+
+    /// A copy with the free-form values escaped to sit inside TOML basic strings.
+    /// The `_field` and dependency lines are TOML already.
+    fn toml_escaped(&self) -> Self {
+        TemplateContext {
+            project_name: toml_escape(&self.project_name),
+            package_name: toml_escape(&self.package_name),
+            command_name: toml_escape(&self.command_name),
+            description: toml_escape(&self.description),
+            author: toml_escape(&self.author),
+            version: toml_escape(&self.version),
+            ..self.clone()
+        }
+    }
+
+    /// The value of the placeholder `name`, if there is one.
+    fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "project_name" => Some(&self.project_name),
+            "package_name" => Some(&self.package_name),
+            "command_name" => Some(&self.command_name),
+            "description" => Some(&self.description),
+            "author" => Some(&self.author),
+            "year" => Some(&self.year),
+            "date" => Some(&self.date),
+            "version" => Some(&self.version),
+            "security_email" => Some(&self.security_email),
+            "python_version" => Some(&self.python_version),
+            "python_tag" => Some(&self.python_tag),
+            "tox_envs" => Some(&self.tox_envs),
+            "nox_pythons" => Some(&self.nox_pythons),
+            "source_dir" => Some(&self.source_dir),
+            "test_dir" => Some(&self.test_dir),
+            "config_names" => Some(&self.config_names),
+            "license_field" => Some(&self.license_field),
+            "dependencies" => Some(&self.dependencies),
+            "requirements" => Some(&self.requirements),
+            "dev_dependencies" => Some(&self.dev_dependencies),
+            "build_system" => Some(&self.build_system),
+            "backend_tool_field" => Some(&self.backend_tool_field),
+            "typing_field" => Some(&self.typing_field),
+            "setup_license_field" => Some(&self.setup_license_field),
+            "install_requires" => Some(&self.install_requires),
+            "setup_packages_field" => Some(&self.setup_packages_field),
+            "setup_find_field" => Some(&self.setup_find_field),
+            "logging_field" => Some(&self.logging_field),
+            "gitignore_patterns" => Some(&self.gitignore_patterns),
+            _ => None,
+        }
+    }
+}
+
+/// Errors found while rendering a template.
+#[derive(Debug, PartialEq)]
+pub enum TemplateError {
+    /// The template uses a placeholder the [`TemplateContext`] does not define.
+    MissingVariable(String),
+    /// A `{{` is never closed by `}}`.
+    UnclosedPlaceholder,
+    /// A file of the [`SkeletonOptions::template_overrides_dir`] can not be read,
+    /// with the reason.
+    UnreadableOverride(PathBuf, String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::MissingVariable(name) => {
+                write!(f, "The template variable '{name}' is not defined")
+            }
+            TemplateError::UnclosedPlaceholder => write!(f, "A template placeholder is not closed"),
+            TemplateError::UnreadableOverride(path, reason) => {
+                write!(
+                    f,
+                    "The template {} can not be read: {reason}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// Escapes `value` for a TOML basic string (`"..."`).
+fn toml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Replaces every `{{name}}` placeholder of `template` with its value in `ctx`.
+///
+/// Spaces inside the braces are ignored, so `{{ author }}` works too. Single braces
+/// are left alone, which keeps Python f-strings and dicts intact.
+///
+/// # Errors
+///
+/// Returns [`TemplateError::MissingVariable`] for an unknown placeholder instead
+/// of leaving it in the output, and [`TemplateError::UnclosedPlaceholder`] if a
+/// `{{` has no matching `}}`.
+///
+/// # Examples
+///
+/// ```
+/// use python_skeleton::files_builder::{TemplateContext, render};
+///
+/// let ctx = TemplateContext {
+///     author: "Jane Doe".to_string(),
+///     year: "2025".to_string(),
+///     ..Default::default()
+/// };
+/// assert_eq!(render("(c) {{year}} {{ author }}", &ctx).unwrap(), "(c) 2025 Jane Doe");
+/// assert!(render("{{licence}}", &ctx).is_err());
+/// ```
+pub fn render(template: &str, ctx: &TemplateContext) -> Result<String, TemplateError> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or(TemplateError::UnclosedPlaceholder)?;
+        let name = after[..end].trim();
+        match ctx.get(name) {
+            Some(value) => rendered.push_str(value),
+            None => return Err(TemplateError::MissingVariable(name.to_string())),
+        }
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// The runtime dependencies of the project: the ones asked for, plus those the
+/// generated `db.py` and `env.py` modules import.
+fn dependencies(options: &SkeletonOptions) -> Vec<&str> {
+    let mut dependencies = Vec::new();
+    dependencies.extend(match options.database {
+        Database::Oracle => &["oracledb", "sqlalchemy"][..],
+        Database::Postgres => &["psycopg[binary]", "sqlalchemy"],
+        Database::MySql => &["pymysql", "sqlalchemy"],
+        Database::Sqlite => &["sqlalchemy"],
+        Database::None => &[],
+    });
+    dependencies.extend(options.dependencies.iter().map(String::as_str));
+    if options.include_env {
+        dependencies.push("pyyaml");
+    }
+    dependencies
+}
+
+/// The Python versions the project is tested with: from the targeted one up to
+/// [`files_content::PYTHON_VERSION`], or only the targeted one when it is newer.
+fn supported_versions(options: &SkeletonOptions) -> Vec<String> {
+    let minor = |version: &str| match version.split_once('.') {
+        Some(("3", minor)) => minor.parse::<u32>().ok(),
+        _ => None,
+    };
+    match (
+        minor(&options.python_version),
+        minor(files_content::PYTHON_VERSION),
+    ) {
+        (Some(first), Some(latest)) if first <= latest => {
+            (first..=latest).map(|minor| format!("3.{minor}")).collect()
+        }
+        _ => vec![options.python_version.clone()],
+    }
+}
+
+/// The dev dependencies of the project: the ones asked for, plus the docs tool
+/// and the test runner.
+fn dev_dependencies(options: &SkeletonOptions) -> Vec<&str> {
+    let mut dev_dependencies: Vec<_> = options
+        .dev_dependencies
+        .iter()
+        .map(String::as_str)
+        .collect();
+    if options.include_doc_dir {
+        dev_dependencies.push(match options.doc_engine {
+            DocEngine::MkDocs => "mkdocs-material",
+            DocEngine::Sphinx => "sphinx",
+        });
+    }
+    match options.test_runner {
+        TestRunner::Tox => dev_dependencies.push("tox"),
+        TestRunner::Nox => dev_dependencies.push("nox"),
+        TestRunner::None => {}
+    }
+    dev_dependencies
+}
+
+/// The `[build-system]` lines and the tool table of the chosen backend. Only
+/// `setuptools` finds the `top_package` without being told where it is.
+fn build_backend(options: &SkeletonOptions, top_package: &str) -> (String, String) {
+    let package_path = match options.layout {
+        Layout::SrcLayout => format!("src/{top_package}"),
+        Layout::FlatLayout => top_package.to_string(),
+    };
+    let (requires, backend, tool) = match backend(options) {
+        BuildBackend::Setuptools => ("setuptools >= 70.0", "setuptools.build_meta", String::new()),
+        BuildBackend::Hatchling => (
+            "hatchling",
+            "hatchling.build",
+            format!("[tool.hatch.build.targets.wheel]\npackages = [\"{package_path}\"]\n\n"),
         ),
-        (
-            format!("{root_name}/src/{package_name}/__init__.py"),
-            files_content::SAMPLE_INIT.to_string(),
+        BuildBackend::Poetry => {
+            let from = match options.layout {
+                Layout::SrcLayout => ", from = \"src\"",
+                Layout::FlatLayout => "",
+            };
+            (
+                "poetry-core >= 2.0.0, < 3.0.0",
+                "poetry.core.masonry.api",
+                format!("[tool.poetry]\npackages = [{{ include = \"{top_package}\"{from} }}]\n\n"),
+            )
+        }
+        BuildBackend::Pdm => (
+            "pdm-backend",
+            "pdm.backend",
+            "[tool.pdm]\ndistribution = true\n\n".to_string(),
         ),
-        (
-            format!("{root_name}/src/{package_name}/env.py"),
-            files_content::SAMPLE_ENV.to_string(),
+    };
+    (
+        format!("requires = [\"{requires}\"]\nbuild-backend = \"{backend}\"\n"),
+        tool,
+    )
+}
+
+/// The build backend of `options`: a legacy `setup.py` needs `setuptools`.
+fn backend(options: &SkeletonOptions) -> BuildBackend {
+    match options.packaging {
+        PackagingStyle::Modern => options.backend,
+        PackagingStyle::Legacy => BuildBackend::Setuptools,
+    }
+}
+
+/// The `[options]` lines and the `[options.packages.find]` table of
+/// `setup.cfg`, finding the `top_package` in the directory of the [`Layout`].
+fn setup_packages(options: &SkeletonOptions, top_package: &str) -> (String, String) {
+    let find = if options.namespace_package {
+        "find_namespace:"
+    } else {
+        "find:"
+    };
+    let (packages, mut find_table) = match options.layout {
+        Layout::SrcLayout => (
+            format!("package_dir =\n    = src\npackages = {find}\n"),
+            "[options.packages.find]\nwhere = src\n\n".to_string(),
         ),
-        (
-            format!("{root_name}/src/{package_name}/db.py"),
-            files_content::SAMPLE_DB.to_string(),
+        Layout::FlatLayout => (
+            format!("packages = {find}\n"),
+            format!(
+                "[options.packages.find]\ninclude =\n    {top_package}\n    {top_package}.*\n\n"
+            ),
         ),
+    };
+    if options.include_typing {
+        find_table.push_str(&format!(
+            "[options.package_data]\n{} = py.typed\n\n",
+            options.package_name
+        ));
+    }
+    (packages, find_table)
+}
+
+/// The `pyproject.toml` tables for a typed package. The other backends ship
+/// `py.typed` without being told.
+fn typing_tables(options: &SkeletonOptions) -> String {
+    if !options.include_typing {
+        return String::new();
+    }
+    let mut tables = String::new();
+    if backend(options) == BuildBackend::Setuptools {
+        tables.push_str(&format!(
+            "[tool.setuptools.package-data]\n\"{}\" = [\"py.typed\"]\n\n",
+            toml_escape(&options.package_name)
+        ));
+    }
+    tables.push_str(&format!(
+        "[tool.mypy]\npython_version = \"{}\"\nstrict = true\n\n",
+        toml_escape(&options.python_version)
+    ));
+    tables
+}
+
+/// The current `(year, month, day)` in UTC, from the system clock.
+fn current_date() -> (i64, i64, i64) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+    // Days to civil date, from Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400;
+    // March-based years: January and February belong to the next civil year.
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Maps project file paths to their respective boilerplate content.
+///
+/// This internal function retrieves the templates from [`files_content`] and
+/// renders each one with a [`TemplateContext`] built from `options`.
+///
+/// A dotted `package_name` (`acme.client`) places the package modules in the
+/// innermost directory and an `__init__.py` in every parent package.
+///
+/// Returns a [`Vec`] of tuples containing `(file_path, file_content)`, or the
+/// first [`TemplateError`] found.
+pub(crate) fn get_files(
+    options: &SkeletonOptions,
+) -> Result<Vec<(PathBuf, String)>, TemplateError> {
+    let root = PathBuf::from(&options.project_name);
+    let test_dir = options.test_dir_name();
+    let segments: Vec<&str> = options.package_name.split('.').collect();
+    let source_dir = options.source_dir();
+    let mut parent_dir = source_dir.clone();
+    let mut parent_inits = Vec::new();
+    for segment in &segments[..segments.len() - 1] {
+        parent_dir = parent_dir.join(segment);
+        // Namespace packages are made by the lack of `__init__.py`.
+        if options.namespace_package {
+            continue;
+        }
+        parent_inits.push((
+            parent_dir.join("__init__.py"),
+            files_content::SAMPLE_PARENT_INIT,
+        ));
+    }
+    let package_dir = segments
+        .iter()
+        .fold(source_dir, |dir, segment| dir.join(segment));
+    let mut templates = Vec::from([
+        (root.join("README.md"), files_content::SAMPLE_README),
+        (root.join(".gitignore"), files_content::SAMPLE_GITIGNORE),
         (
-            format!("{root_name}/test/sample_test.py"),
-            files_content::SAMPLE_TEST.to_string(),
+            package_dir.join("__init__.py"),
+            if options.include_env {
+                files_content::SAMPLE_INIT
+            } else {
+                files_content::SAMPLE_INIT_NO_ENV
+            },
         ),
         (
-            format!("{root_name}/src/{package_name}/main.py"),
-            files_content::SAMPLE_MAIN.to_string(),
+            root.join(test_dir).join("sample_test.py"),
+            files_content::SAMPLE_TEST,
         ),
+        (package_dir.join("main.py"), files_content::SAMPLE_MAIN),
         (
-            format!("{root_name}/config/DEV.yaml"),
-            files_content::SAMPLE_CONFIG.to_string(),
+            package_dir.join("__main__.py"),
+            files_content::SAMPLE_DUNDER_MAIN,
         ),
-    ])
+    ]);
+    for environment in &options.environments {
+        templates.push((
+            root.join("config").join(format!("{environment}.yaml")),
+            files_content::SAMPLE_CONFIG,
+        ));
+    }
+    templates.extend(parent_inits);
+    if options.include_env {
+        templates.push((package_dir.join("env.py"), files_content::SAMPLE_ENV));
+    }
+    if options.include_logging {
+        templates.push((
+            package_dir.join("logging_config.py"),
+            files_content::SAMPLE_LOGGING,
+        ));
+    }
+    if options.include_requirements_txt {
+        templates.push((
+            root.join("requirements.txt"),
+            files_content::SAMPLE_REQUIREMENTS,
+        ));
+    }
+    if options.include_python_version_file {
+        templates.push((
+            root.join(".python-version"),
+            files_content::SAMPLE_PYTHON_VERSION,
+        ));
+    }
+    if options.include_version_file {
+        templates.push((root.join("VERSION"), files_content::SAMPLE_VERSION));
+    }
+    if options.include_conftest {
+        templates.push((
+            root.join(test_dir).join("conftest.py"),
+            files_content::SAMPLE_CONFTEST,
+        ));
+    }
+    if options.include_typing {
+        templates.push((package_dir.join("py.typed"), files_content::SAMPLE_PY_TYPED));
+    }
+    if let Some(db) = files_content::database_template(options.database) {
+        templates.push((package_dir.join("db.py"), db));
+    }
+    if options.include_doc_dir {
+        let (config, index) = files_content::doc_templates(options.doc_engine);
+        templates.extend(match options.doc_engine {
+            DocEngine::MkDocs => [
+                (root.join("mkdocs.yml"), config),
+                (root.join("docs").join("index.md"), index),
+            ],
+            DocEngine::Sphinx => [
+                (root.join("docs").join("conf.py"), config),
+                (root.join("docs").join("index.rst"), index),
+            ],
+        });
+    }
+    if let Some(runner) = files_content::test_runner_template(options.test_runner) {
+        let file_name = match options.test_runner {
+            TestRunner::Nox => "noxfile.py",
+            _ => "tox.ini",
+        };
+        templates.push((root.join(file_name), runner));
+    }
+    if options.include_ci {
+        templates.push((
+            root.join(".github").join("workflows").join("ci.yml"),
+            files_content::SAMPLE_CI_GITHUB,
+        ));
+    }
+    if options.include_docker {
+        templates.push((root.join("Dockerfile"), files_content::SAMPLE_DOCKERFILE));
+        templates.push((
+            root.join(".dockerignore"),
+            files_content::SAMPLE_DOCKERIGNORE,
+        ));
+    }
+    if options.include_precommit {
+        templates.push((
+            root.join(".pre-commit-config.yaml"),
+            files_content::SAMPLE_PRECOMMIT,
+        ));
+    }
+    if options.include_editorconfig {
+        templates.push((
+            root.join(".editorconfig"),
+            files_content::SAMPLE_EDITORCONFIG,
+        ));
+    }
+    if options.include_community_files {
+        templates.push((
+            root.join("CONTRIBUTING.md"),
+            files_content::SAMPLE_CONTRIBUTING,
+        ));
+        templates.push((
+            root.join("CODE_OF_CONDUCT.md"),
+            files_content::SAMPLE_CODE_OF_CONDUCT,
+        ));
+    }
+    if options.include_makefile {
+        templates.push((root.join("Makefile"), files_content::SAMPLE_MAKEFILE));
+    }
+    if options.include_changelog {
+        templates.push((root.join("CHANGELOG.md"), files_content::SAMPLE_CHANGELOG));
+    }
+    if options.include_security {
+        templates.push((root.join("SECURITY.md"), files_content::SAMPLE_SECURITY));
+    }
+    if let Some(license) = files_content::license_template(options.license) {
+        templates.push((root.join("LICENSE"), license));
+    }
+    if options.packaging == PackagingStyle::Legacy {
+        templates.push((root.join("setup.cfg"), files_content::SAMPLE_SETUP_CFG));
+        templates.push((root.join("setup.py"), files_content::SAMPLE_SETUP_PY));
+    }
+    let ctx = TemplateContext::from_options(options);
+    let mut files = templates
+        .into_iter()
+        .map(|(file_name, template)| {
+            let content = match template_override(options, &root, &file_name)? {
+                Some(template_override) => render(&template_override, &ctx)?,
+                None => tidy(&render(template, &ctx)?),
+            };
+            Ok((file_name, content))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Quotes or backslashes in the values must not break the TOML strings.
+    let pyproject = root.join("pyproject.toml");
+    let content = match template_override(options, &root, &pyproject)? {
+        Some(template_override) => render(&template_override, &ctx.toml_escaped())?,
+        None => tidy(&render(
+            files_content::SAMPLE_PYPROJECT,
+            &ctx.toml_escaped(),
+        )?),
+    };
+    files.insert(1, (pyproject, content));
+    // Extra files replace the built-in ones with the same path.
+    for (path, content) in &options.extra_files {
+        let file_name = path
+            .components()
+            .fold(root.clone(), |file, part| file.join(part));
+        let content = render(content, &ctx)?;
+        match files.iter_mut().find(|(name, _)| *name == file_name) {
+            Some(file) => file.1 = content,
+            None => files.push((file_name, content)),
+        }
+    }
+    Ok(files
+        .into_iter()
+        .map(|(file_name, content)| (options.relative_to_output(file_name), content))
+        .collect())
+}
+
+/// The template overriding the built-in `file_name` in the
+/// [`SkeletonOptions::template_overrides_dir`], found at its path relative to
+/// the project `root`, if there is one. Overrides are read from the real disk
+/// with `std::fs`, whatever [`FileSystem`] the project is built into.
+fn template_override(
+    options: &SkeletonOptions,
+    root: &Path,
+    file_name: &Path,
+) -> Result<Option<String>, TemplateError> {
+    let Some(overrides_dir) = &options.template_overrides_dir else {
+        return Ok(None);
+    };
+    let path = overrides_dir.join(file_name.strip_prefix(root).unwrap_or(file_name));
+    if !path.is_file() {
+        return Ok(None);
+    }
+    debug!("Using the template override {}", path.display());
+    match read_to_string(&path) {
+        Ok(template) => Ok(Some(template)),
+        Err(error) => Err(TemplateError::UnreadableOverride(path, error.to_string())),
+    }
+}
+
+/// Trims the trailing whitespace of every line of `content` and ends it with a
+/// single newline, so the generated files pass the ruff checks of the project.
+/// Empty files, such as `py.typed`, stay empty. Only the built-in templates are
+/// tidied: the files of the user are written as given.
+fn tidy(content: &str) -> String {
+    let lines: Vec<&str> = content.trim_end().lines().map(str::trim_end).collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Reads the user template directory of `options`, if any, and renders the name
+/// and content of each file with the [`TemplateContext`], so a file named
+/// `{{package_name}}.py` becomes `my_package.py`.
+///
+/// Returns the `(file_path, file_content)` tuples of every file under the
+/// project root, sorted, keeping the relative path they have in the template
+/// directory.
+///
+/// # Errors
+///
+/// Returns a [`BuildError::IOError`] if the directory can not be read or a file
+/// is not UTF-8, and a [`BuildError::TemplateError`] if the name or content of a
+/// file can not be rendered, as for the built-in templates.
+pub(crate) fn get_template_dir_files(
+    options: &SkeletonOptions,
+) -> Result<Vec<(PathBuf, String)>, BuildError> {
+    let Some(template_dir) = &options.template_dir else {
+        return Ok(Vec::new());
+    };
+    let ctx = TemplateContext::from_options(options);
+    let mut files = Vec::new();
+    let mut pending = vec![template_dir.clone()];
+    while let Some(dir) = pending.pop() {
+        for entry in read_dir(&dir).map_err(BuildError::IOError)? {
+            let path = entry.map_err(BuildError::IOError)?.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let relative = path.strip_prefix(template_dir).unwrap_or(&path);
+            let mut file_path = PathBuf::from(&options.project_name);
+            for component in relative.components() {
+                let name = component.as_os_str().to_string_lossy();
+                file_path.push(render(&name, &ctx).map_err(BuildError::TemplateError)?);
+            }
+            let template = read_to_string(&path).map_err(BuildError::IOError)?;
+            let content = render(&template, &ctx).map_err(BuildError::TemplateError)?;
+            debug!(
+                "Rendered template {} as {}",
+                path.display(),
+                file_path.display()
+            );
+            files.push((options.relative_to_output(file_path), content));
+        }
+    }
+    files.sort();
+    Ok(files)
 }
 
 /// Populates the project structure with boilerplate files.
 ///
 /// This function iterates through a predefined list of files and writes them
-/// to the disk. It assumes the directory structure already exists.
+/// to the disk, writing one line to `progress` as each one is created and
+/// returning the path of every file it created. It assumes the directory
+/// structure already exists.
+///
+/// Files go through [`OsFileSystem`]: each one is written to a temporary sibling
+/// and renamed into place, so an interrupted build never leaves a partially
+/// written file behind.
 ///
 /// # Arguments
 ///
-/// * `root_name` - The name of the project root directory.
-/// * `package_name` - The internal package name (used for the `src` subfolder).
-/// * `verbose` - If true, prints a confirmation message to stdout for every file created.
+/// * `parent_dir` - The base path where the project root was created. Any
+///   [`Path`] works; a `&PathBuf` deref-coerces to it.
+/// * `options` - The project and package names, the [`Layout`] and the template
+///   values. Names are used as given, they are only validated by
+///   [`SkeletonOptions::build`]. Dotted package names (`acme.client`) get an
+///   `__init__.py` in every nested level.
+/// * `progress` - Where the created files are reported, e.g. [`io::stdout`] or
+///   [`io::sink`]. Failing to write it does not fail the build.
 ///
 /// # Errors
 ///
-/// Returns an [`io::Error`] if:
+/// Returns an [`io::Error`], naming the failing file when writing, if:
+/// * A template can not be rendered ([`io::ErrorKind::InvalidData`]).
+/// * A file exists and the [`OverwritePolicy`] is `Error`
+///   ([`io::ErrorKind::AlreadyExists`]).
 /// * The target directory does not exist.
 /// * The program lacks write permissions for the target paths.
 /// * The disk is full or another I/O failure occurs during writing.
@@ -90,20 +855,161 @@ fn get_files(root_name: &str, package_name: &str) -> Vec<(String, String)> {
 /// # Examples
 ///
 /// ```no_run
+/// use std::path::PathBuf;
+/// use python_skeleton::SkeletonOptions;
 /// use python_skeleton::files_builder::make_files;
 ///
 /// fn main() -> std::io::Result<()> {
-///     make_files("my_project", "my_app", true)?;
+///     let path = PathBuf::from("./projects");
+///     let options = SkeletonOptions::new("My-Project", "my_app");
+///     let created = make_files(&path, &options, &mut std::io::stdout())?;
+///     println!("Created {} files", created.len());
 ///     Ok(())
 /// }
 /// ```
-pub fn make_files(root_name: &str, package_name: &str, verbose: bool) -> io::Result<()> {
-    let files = get_files(root_name, package_name);
-    for (file_name, content) in files.iter() {
-        let mut file = File::create(file_name)?;
-        file.write_all(content.as_bytes())?;
-        if verbose {
-            println!("Created file {}", file_name);
+pub fn make_files(
+    parent_dir: &Path,
+    options: &SkeletonOptions,
+    progress: &mut dyn Write,
+) -> io::Result<Vec<PathBuf>> {
+    make_files_in(&OsFileSystem, parent_dir, options, progress)
+}
+
+/// Same as [`make_files`], writing the files to `fs` instead of the disk.
+///
+/// # Errors
+///
+/// Same as [`make_files`], with the errors of `fs` instead of the disk ones.
+pub fn make_files_in(
+    fs: &impl FileSystem,
+    parent_dir: &Path,
+    options: &SkeletonOptions,
+    progress: &mut dyn Write,
+) -> io::Result<Vec<PathBuf>> {
+    let files =
+        get_files(options).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    let mut created = Vec::new();
+    create_files(
+        fs,
+        parent_dir,
+        &files,
+        options.overwrite,
+        &mut created,
+        progress,
+    )
+    .map_err(with_path)?;
+    Ok(created)
+}
+
+/// Does the work of [`make_files_in`], writing the rendered `files` and pushing each
+/// one into `created` once it is in place. Files that existed before are handled
+/// by the `overwrite` policy and never recorded, so a rollback can not delete
+/// user data.
+///
+/// The files are split in contiguous chunks written by parallel threads, and
+/// `created` keeps the order of `files`. The threads send each file they create
+/// back to this one, which reports it to `progress` while they keep writing, so
+/// the lines come in the order the files were written. After the first error
+/// the remaining threads stop, and the error is returned once every thread has
+/// finished, with everything written so far recorded for the rollback and
+/// reported. The error comes with the file that could not be written.
+pub(crate) fn create_files(
+    fs: &impl FileSystem,
+    parent_dir: &Path,
+    files: &[(PathBuf, String)],
+    overwrite: OverwritePolicy,
+    created: &mut Vec<PathBuf>,
+    progress: &mut dyn Write,
+) -> PathResult {
+    let workers = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .clamp(1, files.len().max(1));
+    let chunk_size = files.len().div_ceil(workers).max(1);
+    let failed = &AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+    let results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = files
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let sender = sender.clone();
+                scope.spawn(move || {
+                    let mut chunk_created = Vec::new();
+                    let result = write_files(
+                        fs,
+                        parent_dir,
+                        chunk,
+                        overwrite,
+                        failed,
+                        &sender,
+                        &mut chunk_created,
+                    );
+                    if result.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                    (chunk_created, result)
+                })
+            })
+            .collect();
+        // The threads hold the only senders left, so this ends with the last one.
+        drop(sender);
+        for path in receiver {
+            let _ = writeln!(progress, "Created file {}", path.display());
+        }
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+    let mut first_error = None;
+    for (chunk_created, result) in results {
+        created.extend(chunk_created);
+        if let Err(error) = result {
+            first_error.get_or_insert(error);
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// Writes one chunk of [`create_files`] in order, giving up as soon as `failed`
+/// is set by another thread, and sending each created file to `progress`.
+fn write_files(
+    fs: &impl FileSystem,
+    parent_dir: &Path,
+    files: &[(PathBuf, String)],
+    overwrite: OverwritePolicy,
+    failed: &AtomicBool,
+    progress: &Sender<PathBuf>,
+    created: &mut Vec<PathBuf>,
+) -> PathResult {
+    for (file_name, content) in files {
+        if failed.load(Ordering::Relaxed) {
+            break;
+        }
+        let path = parent_dir.join(file_name);
+        let existed = fs.exists(&path);
+        if existed {
+            match overwrite {
+                OverwritePolicy::Error => {
+                    let error = io::Error::new(io::ErrorKind::AlreadyExists, "the file exists");
+                    return Err((path, error));
+                }
+                OverwritePolicy::Skip => {
+                    info!("Skipping existing file {}", path.display());
+                    continue;
+                }
+                OverwritePolicy::Overwrite => {}
+            }
+        }
+        if let Err(error) = fs.write_file(&path, content.as_bytes()) {
+            return Err((path, error));
+        }
+        if !existed {
+            let _ = progress.send(path.clone());
+            created.push(path.clone());
         }
     }
     Ok(())
@@ -111,17 +1017,1036 @@ pub fn make_files(root_name: &str, package_name: &str, verbose: bool) -> io::Res
 
 #[cfg(test)]
 mod tests {
-    use super::make_files;
+    use super::{
+        TemplateContext, TemplateError, create_files, current_date, get_files, make_files, render,
+    };
     use crate::dir_builder::make_dirs;
-    use std::env::current_dir;
-    use std::fs::remove_dir_all;
+    use crate::file_system::OsFileSystem;
+    use crate::{
+        BuildBackend, Database, DocEngine, GitignorePreset, Layout, License, OverwritePolicy,
+        PackagingStyle, SkeletonOptions, TestRunner,
+    };
+    use std::fs::{read_to_string, write};
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    /// The content planned for `name`, if it is among the `files`.
+    fn find_file(files: &[(PathBuf, String)], name: &str) -> Option<String> {
+        files
+            .iter()
+            .find(|(file_name, _)| file_name == Path::new(name))
+            .map(|(_, content)| content.clone())
+    }
 
     #[test]
     fn test_file_creation() {
-        let mut dir = current_dir().unwrap();
-        assert!(make_dirs(&mut dir, "test-build", false, "test_build", false).is_ok());
-        assert!(make_files("test-build", "test_build", false).is_ok());
-        dir.push("test-build");
-        let _ = remove_dir_all(dir);
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build");
+        assert!(make_dirs(dir.path(), &options, &mut io::sink()).is_ok());
+        let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+
+        // README, pyproject, .gitignore, __init__, env, main, __main__, sample test
+        // and config.
+        assert_eq!(created.len(), 9);
+        assert_eq!(created.len(), get_files(&options).unwrap().len());
+        assert!(created.iter().all(|file| file.is_file()));
+    }
+
+    #[test]
+    fn test_file_creation_in_current_dir() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").use_current_as_root(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+
+        assert_eq!(created.len(), 9);
+        assert!(created.contains(&dir.path().join("README.md")));
+        assert!(dir.path().join("src/test_build/__init__.py").is_file());
+        assert!(!dir.path().join("test-build").exists());
+    }
+
+    #[test]
+    fn test_no_trailing_whitespace() {
+        let options = SkeletonOptions::new("test-build", "acme.client")
+            .description("Does things.  ")
+            .docs(true)
+            .ci(true)
+            .docker(true)
+            .precommit(true)
+            .editorconfig(true)
+            .community_files(true)
+            .changelog(true)
+            .security(true)
+            .makefile(true)
+            .conftest(true)
+            .requirements_txt(true)
+            .typing(true)
+            .logging(true)
+            .database(Database::Oracle)
+            .test_runner(TestRunner::Nox)
+            .license(License::Mit);
+        for (file_name, content) in get_files(&options).unwrap() {
+            let file_name = file_name.display();
+            for line in content.lines() {
+                assert_eq!(line, line.trim_end(), "trailing whitespace in {file_name}");
+            }
+            assert!(
+                content.is_empty() || (content.ends_with('\n') && !content.ends_with("\n\n")),
+                "{file_name} does not end with a single newline"
+            );
+        }
+    }
+
+    /// Templates are plain strings, so a typo only shows up in Python: compile
+    /// every generated module with `python3 -m py_compile`, if it is installed.
+    #[test]
+    fn test_python_files_compile() {
+        let python = Command::new("python3").arg("--version").output();
+        if !python.is_ok_and(|output| output.status.success()) {
+            return;
+        }
+        let databases = [
+            Database::Oracle,
+            Database::Postgres,
+            Database::MySql,
+            Database::Sqlite,
+        ];
+        for (index, database) in databases.into_iter().enumerate() {
+            let dir = tempdir().unwrap();
+            let options = SkeletonOptions::new("test-build", "acme.client")
+                .env(index % 2 == 0)
+                .docs(true)
+                .doc_engine(DocEngine::Sphinx)
+                .conftest(true)
+                .logging(true)
+                .test_runner(TestRunner::Nox)
+                .packaging(PackagingStyle::Legacy)
+                .database(database);
+            make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+            let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+            let modules: Vec<_> = created
+                .iter()
+                .filter(|file| file.extension().is_some_and(|extension| extension == "py"))
+                .collect();
+            assert!(modules.len() >= 6);
+
+            let output = Command::new("python3")
+                .args(["-m", "py_compile"])
+                .args(&modules)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "{database:?}: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    #[test]
+    fn test_nested_package_files() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "acme.client.http");
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let src = dir.path().join("test-build/src");
+
+        assert!(src.join("acme/__init__.py").is_file());
+        assert!(src.join("acme/client/__init__.py").is_file());
+        assert!(src.join("acme/client/http/__init__.py").is_file());
+        assert!(src.join("acme/client/http/main.py").is_file());
+        assert!(!src.join("acme/main.py").exists());
+        assert_eq!(created.len(), 11);
+    }
+
+    #[test]
+    fn test_namespace_package_files() {
+        let dir = tempdir().unwrap();
+        let options =
+            SkeletonOptions::new("test-build", "acme.client.http").namespace_package(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let src = dir.path().join("test-build/src");
+
+        assert!(src.join("acme/client").is_dir());
+        assert!(!src.join("acme/__init__.py").exists());
+        assert!(!src.join("acme/client/__init__.py").exists());
+        assert!(src.join("acme/client/http/__init__.py").is_file());
+        assert!(src.join("acme/client/http/main.py").is_file());
+        assert_eq!(created.len(), 9);
+    }
+
+    #[test]
+    fn test_flat_layout_files() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").layout(Layout::FlatLayout);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let root = dir.path().join("test-build");
+
+        assert!(root.join("test_build/__init__.py").is_file());
+        let readme = read_to_string(root.join("README.md")).unwrap();
+        assert!(readme.contains("|- test_build/                # Source code"));
+        assert!(!readme.contains("|- src/"));
+    }
+
+    #[test]
+    fn test_tests_directory_files() {
+        let files =
+            get_files(&SkeletonOptions::new("test-build", "test_build").tests_dir(true)).unwrap();
+        let content = |name: &str| find_file(&files, name).unwrap();
+
+        assert!(content("test-build/tests/sample_test.py").contains("import pytest"));
+        assert!(content("test-build/pyproject.toml").contains("\"tests/*\" = [\"D\", \"s\"]"));
+        assert!(content("test-build/README.md").contains("|- tests/               # Unit tests"));
+        assert!(
+            !files
+                .iter()
+                .any(|(name, _)| name.starts_with("test-build/test"))
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let ctx = TemplateContext::from_options(
+            &SkeletonOptions::new("test-build", "test_build")
+                .author("Jane Doe")
+                .version("1.2.3"),
+        );
+        assert_eq!(
+            render(
+                "{{project_name}}/{{ package_name }} {{version}} by {{author}}",
+                &ctx
+            )
+            .unwrap(),
+            "test-build/test_build 1.2.3 by Jane Doe"
+        );
+        assert_eq!(render("f\"{x}\" {1: 2}", &ctx).unwrap(), "f\"{x}\" {1: 2}");
+        assert_eq!(
+            render("{{licence}}", &ctx).err().unwrap(),
+            TemplateError::MissingVariable("licence".to_string())
+        );
+        assert_eq!(
+            render("{{author", &ctx).err().unwrap(),
+            TemplateError::UnclosedPlaceholder
+        );
+    }
+
+    #[test]
+    fn test_templates_are_rendered() {
+        let options = SkeletonOptions::new("test-build", "test_build").author("Jane Doe");
+        let files = get_files(&options).unwrap();
+
+        assert!(files.iter().all(|(_, content)| !content.contains("{{")));
+        let pyproject = &files
+            .iter()
+            .find(|(name, _)| name == "test-build/pyproject.toml")
+            .unwrap()
+            .1;
+        assert!(pyproject.contains("name = \"test_build\""));
+        assert!(pyproject.contains("{ name = \"Jane Doe\" }"));
+        assert!(current_date().0 >= 2025);
+    }
+
+    #[test]
+    fn test_template_overrides() {
+        let dir = tempdir().unwrap();
+        write(
+            dir.path().join("README.md"),
+            "# {{project_name}}\nOur own README.  \n",
+        )
+        .unwrap();
+        write(dir.path().join("notes.txt"), "Not a built-in file.\n").unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let defaults = get_files(&options).unwrap();
+        let files = get_files(&options.clone().template_overrides_dir(dir.path())).unwrap();
+
+        assert_eq!(
+            find_file(&files, "test-build/README.md").unwrap(),
+            "# test-build\nOur own README.  \n"
+        );
+        assert!(find_file(&files, "test-build/notes.txt").is_none());
+        assert_eq!(files.len(), defaults.len());
+        for (name, content) in &defaults {
+            if !name.ends_with("README.md") {
+                assert_eq!(
+                    find_file(&files, &name.to_string_lossy()),
+                    Some(content.clone())
+                );
+            }
+        }
+
+        write(dir.path().join("pyproject.toml"), "{{unknown}}").unwrap();
+        assert_eq!(
+            get_files(&options.template_overrides_dir(dir.path())).unwrap_err(),
+            TemplateError::MissingVariable("unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_readme_project_name() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Readme-Build", "readme_build")
+            .description("Scaffolds readable projects.");
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let readme = read_to_string(dir.path().join("Readme-Build/README.md")).unwrap();
+
+        assert!(readme.starts_with("# Readme-Build\nScaffolds readable projects.\n"));
+        assert!(readme.contains("\nReadme-Build/\n|- src/"));
+    }
+
+    #[test]
+    fn test_license_file() {
+        let options = SkeletonOptions::new("test-build", "test_build").author("Jane Doe");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/LICENSE").is_none());
+        assert!(
+            !find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("license")
+        );
+
+        let files = get_files(&options.license(License::Mit)).unwrap();
+        let license = find_file(&files, "test-build/LICENSE").unwrap();
+        assert!(license.starts_with("MIT License"));
+        assert!(license.contains(&format!("Copyright (c) {} Jane Doe", current_date().0)));
+        assert!(
+            find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("readme = \"README.md\"\nlicense = \"MIT\"\nauthors")
+        );
+    }
+
+    #[test]
+    fn test_ci_workflow() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").ci(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let workflow =
+            read_to_string(dir.path().join("test-build/.github/workflows/ci.yml")).unwrap();
+        let pyproject = read_to_string(dir.path().join("test-build/pyproject.toml")).unwrap();
+
+        assert!(workflow.contains("run: pytest"));
+        assert!(workflow.contains("python-version: \"3.14\""));
+        assert!(pyproject.contains("requires-python = \"==3.14.*\""));
+    }
+
+    #[test]
+    fn test_docker_files() {
+        let options = SkeletonOptions::new("test-build", "acme.client");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/Dockerfile").is_none());
+        assert!(find_file(&files, "test-build/.dockerignore").is_none());
+
+        let files = get_files(&options.docker(true)).unwrap();
+        let dockerfile = find_file(&files, "test-build/Dockerfile").unwrap();
+        assert!(dockerfile.contains("FROM python:3.14 AS builder"));
+        assert!(dockerfile.contains("FROM python:3.14-slim"));
+        assert!(dockerfile.contains("COPY src/ src/"));
+        assert!(dockerfile.contains("\"acme.client.main\""));
+        assert!(
+            find_file(&files, "test-build/.dockerignore")
+                .unwrap()
+                .contains(".venv")
+        );
+    }
+
+    #[test]
+    fn test_docs_config() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").docs(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let root = dir.path().join("test-build");
+        assert!(root.join("docs").is_dir());
+        let mkdocs = read_to_string(root.join("mkdocs.yml")).unwrap();
+        assert!(mkdocs.starts_with("site_name: test-build\n"));
+        assert!(root.join("docs/index.md").is_file());
+
+        let files = get_files(&options.doc_engine(DocEngine::Sphinx)).unwrap();
+        assert!(find_file(&files, "test-build/mkdocs.yml").is_none());
+        let conf = find_file(&files, "test-build/docs/conf.py").unwrap();
+        assert!(conf.contains("project = \"test-build\""));
+        let index = find_file(&files, "test-build/docs/index.rst").unwrap();
+        assert!(index.contains(".. automodule:: test_build.main"));
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let dev = table["dependency-groups"]["dev"].as_array().unwrap();
+        assert!(dev.contains(&"sphinx".into()));
+
+        let files = get_files(&SkeletonOptions::new("test-build", "test_build")).unwrap();
+        assert!(
+            !files
+                .iter()
+                .any(|(name, _)| name.starts_with("test-build/docs"))
+        );
+    }
+
+    #[test]
+    fn test_community_files() {
+        let options = SkeletonOptions::new("test-build", "test_build").author("Jane Doe");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/CONTRIBUTING.md").is_none());
+        assert!(find_file(&files, "test-build/CODE_OF_CONDUCT.md").is_none());
+
+        let files = get_files(&options.community_files(true)).unwrap();
+        let contributing = find_file(&files, "test-build/CONTRIBUTING.md").unwrap();
+        assert!(contributing.starts_with("# Contributing to test-build\n"));
+        assert!(contributing.contains("Maintainer: Jane Doe."));
+        let conduct = find_file(&files, "test-build/CODE_OF_CONDUCT.md").unwrap();
+        assert!(conduct.contains("maintainers of test-build"));
+        assert!(conduct.contains("the maintainer, Jane Doe."));
+    }
+
+    #[test]
+    fn test_python_version_file() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/.python-version").is_none());
+
+        for options in [
+            options.clone().python_version_file(true),
+            options.python_version("3.12").python_version_file(true),
+        ] {
+            let files = get_files(&options).unwrap();
+            let version = find_file(&files, "test-build/.python-version").unwrap();
+            let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .parse()
+                .unwrap();
+            let pin = table["project"]["requires-python"].as_str().unwrap();
+            assert_eq!(pin, format!("=={}.*", version.trim_end()));
+        }
+    }
+
+    #[test]
+    fn test_version_file() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/VERSION").is_none());
+
+        let options = options.version("1.4.2").version_file(true);
+        let files = get_files(&options).unwrap();
+        let version = find_file(&files, "test-build/VERSION").unwrap();
+        assert_eq!(version, "1.4.2\n");
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            table["project"]["version"].as_str(),
+            Some(version.trim_end())
+        );
+        let init = find_file(&files, "test-build/src/test_build/__init__.py").unwrap();
+        assert!(init.contains(&format!("__version__ = \"{}\"", version.trim_end())));
+    }
+
+    #[test]
+    fn test_python_version() {
+        let options = SkeletonOptions::new("test-build", "test_build")
+            .typing(true)
+            .python_version("3.12");
+        let files = get_files(&options).unwrap();
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            table["project"]["requires-python"].as_str(),
+            Some("==3.12.*")
+        );
+        assert_eq!(
+            table["tool"]["ruff"]["target-version"].as_str(),
+            Some("py312")
+        );
+        assert_eq!(
+            table["tool"]["mypy"]["python_version"].as_str(),
+            Some("3.12")
+        );
+    }
+
+    #[test]
+    fn test_pytest_config() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build")
+            .tests_dir(true)
+            .conftest(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let conftest = read_to_string(dir.path().join("test-build/tests/conftest.py")).unwrap();
+        assert!(conftest.contains("@pytest.fixture\ndef sample_data():"));
+
+        let pyproject = read_to_string(dir.path().join("test-build/pyproject.toml")).unwrap();
+        let table: toml::Table = pyproject.parse().unwrap();
+        assert_eq!(
+            table["tool"]["pytest"]["ini_options"]["testpaths"],
+            toml::Value::from(vec!["tests"])
+        );
+        let files = get_files(&SkeletonOptions::new("test-build", "test_build")).unwrap();
+        assert!(!files.iter().any(|(name, _)| name.ends_with("conftest.py")));
+    }
+
+    #[test]
+    fn test_makefile() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "acme.client").makefile(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let makefile = read_to_string(dir.path().join("test-build/Makefile")).unwrap();
+
+        assert!(makefile.contains("\ntest:\n\tuv run pytest test\n"));
+        assert!(makefile.contains("\nrun:\n\tuv run python -m acme.client\n"));
+        let files = get_files(&SkeletonOptions::new("test-build", "test_build")).unwrap();
+        assert!(find_file(&files, "test-build/Makefile").is_none());
+    }
+
+    #[test]
+    fn test_security_policy() {
+        let options = SkeletonOptions::new("Test-Build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "Test-Build/SECURITY.md").is_none());
+
+        let options = options.security(true).security_email("security@acme.org");
+        let files = get_files(&options).unwrap();
+        let security = find_file(&files, "Test-Build/SECURITY.md").unwrap();
+        assert!(security.contains("latest release of Test-Build"));
+        assert!(security.contains("send an email to security@acme.org"));
+        assert!(!security.contains("{{"));
+    }
+
+    #[test]
+    fn test_test_runner() {
+        let options = SkeletonOptions::new("test-build", "test_build").python_version("3.12");
+        let files = get_files(&options.clone().test_runner(TestRunner::Tox)).unwrap();
+        let tox = find_file(&files, "test-build/tox.ini").unwrap();
+        assert!(tox.contains("envlist = py312, py313, py314\n"));
+        assert!(tox.contains("commands = pytest {posargs:test}\n"));
+        assert!(
+            find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("\"tox\",")
+        );
+
+        let files = get_files(&options.clone().test_runner(TestRunner::Nox)).unwrap();
+        let nox = find_file(&files, "test-build/noxfile.py").unwrap();
+        assert!(nox.contains("@nox.session(python=[\"3.12\", \"3.13\", \"3.14\"])"));
+        assert!(find_file(&files, "test-build/tox.ini").is_none());
+
+        let files =
+            get_files(&options.python_version("3.15").test_runner(TestRunner::Tox)).unwrap();
+        let tox = find_file(&files, "test-build/tox.ini").unwrap();
+        assert!(tox.contains("envlist = py315\n"));
+    }
+
+    #[test]
+    fn test_gitignore_preset() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        let gitignore = find_file(&files, "test-build/.gitignore").unwrap();
+        assert!(gitignore.contains("*.py[oc]\n"));
+        assert!(!gitignore.contains("*.parquet"));
+
+        let files = get_files(
+            &options
+                .clone()
+                .gitignore_preset(GitignorePreset::PythonData),
+        )
+        .unwrap();
+        let gitignore = find_file(&files, "test-build/.gitignore").unwrap();
+        assert!(gitignore.contains("*.py[oc]\n"));
+        assert!(gitignore.contains("*.parquet\n"));
+        assert!(gitignore.contains("mlruns/\n"));
+
+        let files = get_files(&options.gitignore_preset(GitignorePreset::PythonWeb)).unwrap();
+        let gitignore = find_file(&files, "test-build/.gitignore").unwrap();
+        assert!(gitignore.contains("staticfiles/\n"));
+        assert!(!gitignore.contains("mlruns/"));
+    }
+
+    #[test]
+    fn test_changelog() {
+        let options = SkeletonOptions::new("test-build", "test_build").version("2.3.0");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/CHANGELOG.md").is_none());
+
+        let files = get_files(&options.changelog(true)).unwrap();
+        let changelog = find_file(&files, "test-build/CHANGELOG.md").unwrap();
+        let (year, month, day) = current_date();
+        assert!(changelog.contains("## [Unreleased]"));
+        assert!(changelog.contains(&format!("## [2.3.0] - {year:04}-{month:02}-{day:02}")));
+        assert!(
+            find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("version = \"2.3.0\"")
+        );
+    }
+
+    #[test]
+    fn test_precommit_config() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/.pre-commit-config.yaml").is_none());
+
+        let files = get_files(&options.precommit(true)).unwrap();
+        let config = find_file(&files, "test-build/.pre-commit-config.yaml").unwrap();
+        assert!(config.contains("repo: https://github.com/astral-sh/ruff-pre-commit"));
+        assert!(config.contains("rev: v0.12.0"));
+    }
+
+    #[test]
+    fn test_editorconfig() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").editorconfig(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let config = read_to_string(dir.path().join("test-build/.editorconfig")).unwrap();
+
+        assert!(config.starts_with("root = true"));
+        assert!(config.contains("[*.py]\nindent_style = space\nindent_size = 4"));
+    }
+
+    #[test]
+    fn test_optional_modules() {
+        let options = SkeletonOptions::new("test-build", "test_build").database(Database::Oracle);
+        let files = get_files(&options).unwrap();
+        let pyproject = find_file(&files, "test-build/pyproject.toml").unwrap();
+        let init = find_file(&files, "test-build/src/test_build/__init__.py").unwrap();
+        assert!(find_file(&files, "test-build/src/test_build/env.py").is_some());
+        assert!(find_file(&files, "test-build/src/test_build/db.py").is_some());
+        assert!(pyproject.contains("    \"oracledb\",\n    \"sqlalchemy\","));
+        assert!(pyproject.contains("    \"pyyaml\"\n]"));
+        assert!(init.contains("load_env()"));
+
+        let files = get_files(&options.database(Database::None).env(false)).unwrap();
+        let pyproject = find_file(&files, "test-build/pyproject.toml").unwrap();
+        let init = find_file(&files, "test-build/src/test_build/__init__.py").unwrap();
+        assert!(find_file(&files, "test-build/src/test_build/env.py").is_none());
+        assert!(find_file(&files, "test-build/src/test_build/db.py").is_none());
+        for dependency in ["oracledb", "sqlalchemy", "pyyaml"] {
+            assert!(!pyproject.contains(dependency));
+        }
+        assert!(!init.contains("load_env"));
+        assert!(init.contains("__version__"));
+    }
+
+    #[test]
+    fn test_database_templates() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/src/test_build/db.py").is_none());
+        assert!(
+            !find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("sqlalchemy")
+        );
+
+        for (database, dialect, driver) in [
+            (Database::Oracle, "oracle", "oracledb"),
+            (Database::Postgres, "postgresql+psycopg", "psycopg[binary]"),
+            (Database::MySql, "mysql+pymysql", "pymysql"),
+            (Database::Sqlite, "sqlite", "sqlalchemy"),
+        ] {
+            let files = get_files(&options.clone().database(database)).unwrap();
+            let db = find_file(&files, "test-build/src/test_build/db.py").unwrap();
+            let pyproject = find_file(&files, "test-build/pyproject.toml").unwrap();
+            assert!(db.contains(&format!("\"{dialect}")));
+            assert!(pyproject.contains(&format!("\"{driver}\"")));
+        }
+    }
+
+    #[test]
+    fn test_build_backends() {
+        for (backend, name, tool) in [
+            (BuildBackend::Setuptools, "setuptools.build_meta", None),
+            (BuildBackend::Hatchling, "hatchling.build", Some("hatch")),
+            (
+                BuildBackend::Poetry,
+                "poetry.core.masonry.api",
+                Some("poetry"),
+            ),
+            (BuildBackend::Pdm, "pdm.backend", Some("pdm")),
+        ] {
+            let options = SkeletonOptions::new("test-build", "test_build").backend(backend);
+            let files = get_files(&options).unwrap();
+            let pyproject = find_file(&files, "test-build/pyproject.toml").unwrap();
+            let table: toml::Table = pyproject.parse().unwrap();
+
+            assert_eq!(table["build-system"]["build-backend"].as_str(), Some(name));
+            assert_eq!(table["project"]["name"].as_str(), Some("test_build"));
+            if let Some(tool) = tool {
+                assert!(table["tool"].get(tool).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_legacy_packaging() {
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let files = get_files(&options).unwrap();
+        assert!(find_file(&files, "test-build/setup.py").is_none());
+        assert!(find_file(&files, "test-build/setup.cfg").is_none());
+
+        let options = options
+            .backend(BuildBackend::Hatchling)
+            .license(License::Mit)
+            .packaging(PackagingStyle::Legacy);
+        let files = get_files(&options).unwrap();
+        assert!(
+            find_file(&files, "test-build/setup.py")
+                .unwrap()
+                .contains("setup()")
+        );
+        let setup_cfg = find_file(&files, "test-build/setup.cfg").unwrap();
+        assert!(setup_cfg.contains("name = test_build\nversion = 0.1.0\n"));
+        assert!(setup_cfg.contains("license = MIT\n"));
+        assert!(setup_cfg.contains("package_dir =\n    = src\npackages = find:\n"));
+        assert!(setup_cfg.contains("[options.packages.find]\nwhere = src\n"));
+        assert!(setup_cfg.contains("install_requires =\n    numpy\n"));
+        assert!(setup_cfg.contains("test-build = test_build.main:main"));
+        // setup.py only runs with setuptools.
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            table["build-system"]["build-backend"].as_str(),
+            Some("setuptools.build_meta")
+        );
+
+        let files = get_files(&options.layout(Layout::FlatLayout).typing(true)).unwrap();
+        let setup_cfg = find_file(&files, "test-build/setup.cfg").unwrap();
+        assert!(!setup_cfg.contains("package_dir"));
+        assert!(setup_cfg.contains("include =\n    test_build\n    test_build.*\n"));
+        assert!(setup_cfg.contains("[options.package_data]\ntest_build = py.typed\n"));
+    }
+
+    #[test]
+    fn test_custom_dependencies() {
+        let options = SkeletonOptions::new("test-build", "test_build")
+            .env(false)
+            .dependencies(["httpx", "rich >= 13"])
+            .dev_dependencies(["pytest"]);
+        let files = get_files(&options).unwrap();
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            table["project"]["dependencies"],
+            toml::Value::from(vec!["httpx", "rich >= 13"])
+        );
+        assert_eq!(
+            table["dependency-groups"]["dev"],
+            toml::Value::from(vec!["pytest"])
+        );
+    }
+
+    #[test]
+    fn test_pyproject_is_valid_toml() {
+        let options = SkeletonOptions::new("test-build", "test_build")
+            .author("Jane \"JD\" Doe\\")
+            .version("1.0\n")
+            .license(License::Mit)
+            .dependencies(["weird\"dep"]);
+        let files = get_files(&options).unwrap();
+        let table: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            table["project"]["authors"][0]["name"].as_str(),
+            Some("Jane \"JD\" Doe\\")
+        );
+        assert_eq!(table["project"]["version"].as_str(), Some("1.0\n"));
+        assert_eq!(table["project"]["license"].as_str(), Some("MIT"));
+        assert_eq!(
+            table["project"]["dependencies"][0].as_str(),
+            Some("weird\"dep")
+        );
+        // Only `pyproject.toml` is escaped, the README keeps the author as given.
+        let readme = find_file(&files, "test-build/README.md").unwrap();
+        assert!(readme.contains("Jane \"JD\" Doe\\."));
+    }
+
+    #[test]
+    fn test_atomic_writes() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "acme.client")
+            .database(Database::Postgres)
+            .license(License::Mit)
+            .ci(true)
+            .docker(true)
+            .precommit(true)
+            .editorconfig(true)
+            .community_files(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        let created = make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let files = get_files(&options).unwrap();
+
+        assert_eq!(created.len(), files.len());
+        for (file_name, content) in &files {
+            assert_eq!(
+                &read_to_string(dir.path().join(file_name)).unwrap(),
+                content
+            );
+        }
+        // No temporary file is left behind.
+        let mut pending = vec![dir.path().to_path_buf()];
+        while let Some(path) = pending.pop() {
+            for entry in path.read_dir().unwrap() {
+                let entry = entry.unwrap().path();
+                assert!(!entry.to_string_lossy().ends_with(".tmp"));
+                if entry.is_dir() {
+                    pending.push(entry);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_overwrite_policies() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build");
+        let readme = dir.path().join("test-build/README.md");
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+
+        write(&readme, "my notes").unwrap();
+        let error = make_files(
+            dir.path(),
+            &options.clone().overwrite(OverwritePolicy::Error),
+            &mut io::sink(),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(read_to_string(&readme).unwrap(), "my notes");
+
+        let created = make_files(
+            dir.path(),
+            &options.clone().overwrite(OverwritePolicy::Skip),
+            &mut io::sink(),
+        )
+        .unwrap();
+        assert_eq!(read_to_string(&readme).unwrap(), "my notes");
+        assert!(!created.contains(&readme));
+        assert!(created.contains(&dir.path().join("test-build/pyproject.toml")));
+
+        let created = make_files(
+            dir.path(),
+            &options.overwrite(OverwritePolicy::Overwrite),
+            &mut io::sink(),
+        )
+        .unwrap();
+        assert!(read_to_string(&readme).unwrap().starts_with("# test-build"));
+        assert!(created.is_empty());
+    }
+
+    #[test]
+    fn test_parallel_writes() {
+        let dir = tempdir().unwrap();
+        let files: Vec<_> = (0..500)
+            .map(|index| {
+                (
+                    PathBuf::from(format!("file_{index}.txt")),
+                    format!("content {index}"),
+                )
+            })
+            .collect();
+        let mut created = Vec::new();
+        let mut progress = Vec::new();
+        create_files(
+            &OsFileSystem,
+            dir.path(),
+            &files,
+            OverwritePolicy::Error,
+            &mut created,
+            &mut progress,
+        )
+        .unwrap();
+
+        let expected: Vec<_> = files
+            .iter()
+            .map(|(file_name, _)| dir.path().join(file_name))
+            .collect();
+        assert_eq!(created, expected);
+        let progress = String::from_utf8(progress).unwrap();
+        assert_eq!(progress.lines().count(), files.len());
+        for (file_name, content) in &files {
+            assert_eq!(
+                &read_to_string(dir.path().join(file_name)).unwrap(),
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn test_parallel_writes_error() {
+        let dir = tempdir().unwrap();
+        let mut files: Vec<_> = (0..500)
+            .map(|index| (PathBuf::from(format!("file_{index}.txt")), String::new()))
+            .collect();
+        files[250].0 = PathBuf::from("missing/file.txt");
+        let mut created = Vec::new();
+        let mut progress = Vec::new();
+        let error = create_files(
+            &OsFileSystem,
+            dir.path(),
+            &files,
+            OverwritePolicy::Error,
+            &mut created,
+            &mut progress,
+        )
+        .unwrap_err();
+
+        assert_eq!(error.0, dir.path().join("missing/file.txt"));
+        assert_eq!(error.1.kind(), io::ErrorKind::NotFound);
+        // Whatever was written before the abort is recorded for the rollback.
+        assert!(created.iter().all(|file| file.is_file()));
+        assert_eq!(dir.path().read_dir().unwrap().count(), created.len());
+        // And reported, even though the build failed.
+        let progress = String::from_utf8(progress).unwrap();
+        assert_eq!(progress.lines().count(), created.len());
+    }
+
+    #[test]
+    fn test_requirements_txt() {
+        let options = SkeletonOptions::new("test-build", "test_build")
+            .database(Database::Postgres)
+            .requirements_txt(true);
+        let files = get_files(&options).unwrap();
+        let requirements = find_file(&files, "test-build/requirements.txt").unwrap();
+        let pyproject: toml::Table = find_file(&files, "test-build/pyproject.toml")
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        let dependencies: Vec<_> = pyproject["project"]["dependencies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|dependency| dependency.as_str().unwrap())
+            .collect();
+        assert!(dependencies.contains(&"psycopg[binary]"));
+        assert_eq!(requirements.lines().collect::<Vec<_>>(), dependencies);
+        assert!(requirements.ends_with('\n'));
+
+        let files = get_files(&options.requirements_txt(false)).unwrap();
+        assert!(find_file(&files, "test-build/requirements.txt").is_none());
+    }
+
+    #[test]
+    fn test_environments() {
+        let dir = tempdir().unwrap();
+        let options =
+            SkeletonOptions::new("test-build", "test_build").environments(["DEV", "PROD"]);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let root = dir.path().join("test-build");
+
+        assert!(root.join("config/DEV.yaml").is_file());
+        assert!(root.join("config/PROD.yaml").is_file());
+        let env = read_to_string(root.join("src/test_build/env.py")).unwrap();
+        assert!(env.contains("\"settings.yaml\",\n                \"DEV.yaml\", \"PROD.yaml\")"));
+
+        let files = get_files(&SkeletonOptions::new("test-build", "test_build")).unwrap();
+        let configs: Vec<_> = files
+            .iter()
+            .filter(|(name, _)| name.starts_with("test-build/config"))
+            .map(|(name, _)| name.as_path())
+            .collect();
+        assert_eq!(configs, [Path::new("test-build/config/DEV.yaml")]);
+    }
+
+    #[test]
+    fn test_logging_config() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "acme.client").logging(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let package = dir.path().join("test-build/src/acme/client");
+        let logging = read_to_string(package.join("logging_config.py")).unwrap();
+        let init = read_to_string(package.join("__init__.py")).unwrap();
+
+        assert!(logging.contains("def get_logger("));
+        assert!(logging.contains("structlog.configure("));
+        assert!(init.contains(
+            "from .env import load_env\n\nfrom .logging_config import get_logger as get_logger\n\n__version__"
+        ));
+
+        let options = SkeletonOptions::new("test-build", "test_build").env(false);
+        let files = get_files(&options).unwrap();
+        assert!(
+            !files
+                .iter()
+                .any(|(name, _)| name.ends_with("logging_config.py"))
+        );
+        let init = find_file(&files, "test-build/src/test_build/__init__.py").unwrap();
+        assert!(init.contains("\"\"\"Packages initiator.\"\"\"\n\n__version__"));
+        let files = get_files(&options.logging(true)).unwrap();
+        let init = find_file(&files, "test-build/src/test_build/__init__.py").unwrap();
+        assert!(
+            init.contains(
+                "\n\nfrom .logging_config import get_logger as get_logger\n\n__version__"
+            )
+        );
+    }
+
+    #[test]
+    fn test_typing_marker() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("test-build", "test_build").typing(true);
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let root = dir.path().join("test-build");
+        let table: toml::Table = read_to_string(root.join("pyproject.toml"))
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert!(root.join("src/test_build/py.typed").is_file());
+        assert_eq!(
+            table["tool"]["setuptools"]["package-data"]["test_build"],
+            toml::Value::from(vec!["py.typed"])
+        );
+        assert_eq!(table["tool"]["mypy"]["strict"].as_bool(), Some(true));
+
+        let files = get_files(&SkeletonOptions::new("test-build", "test_build")).unwrap();
+        assert!(find_file(&files, "test-build/src/test_build/py.typed").is_none());
+        assert!(
+            !find_file(&files, "test-build/pyproject.toml")
+                .unwrap()
+                .contains("mypy")
+        );
+    }
+
+    #[test]
+    fn test_runnable_package() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Test-Build", "test_build");
+        make_dirs(dir.path(), &options, &mut io::sink()).unwrap();
+        make_files(dir.path(), &options, &mut io::sink()).unwrap();
+        let package = dir.path().join("Test-Build/src/test_build");
+        let table: toml::Table = read_to_string(dir.path().join("Test-Build/pyproject.toml"))
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        assert!(package.join("__main__.py").is_file());
+        assert!(
+            read_to_string(package.join("main.py"))
+                .unwrap()
+                .contains("def main():")
+        );
+        assert_eq!(
+            table["project"]["scripts"]["test-build"].as_str(),
+            Some("test_build.main:main")
+        );
     }
 }
@@ -1,14 +1,29 @@
 //! Files sampl contents
 //! Here are allocated all the constant to fill the skeleton files.
+use crate::{Database, DocEngine, GitignorePreset, License, TestRunner};
+
+/// The Python version targeted by the generated projects, unless another one is
+/// chosen with [`crate::SkeletonOptions::python_version`].
+pub const PYTHON_VERSION: &str = "3.14";
+
+// Read by pyenv and uv, it must match the `requires-python` of `pyproject.toml`.
+pub const SAMPLE_PYTHON_VERSION: &str = "{{python_version}}\n";
+
+// The same version as `pyproject.toml` and `__version__`, for release scripts.
+pub const SAMPLE_VERSION: &str = "{{version}}\n";
+
+// The same dependencies as `pyproject.toml`, for tools reading pip files.
+pub const SAMPLE_REQUIREMENTS: &str = "{{requirements}}";
+
 pub const SAMPLE_README: &str = "\
-# README's template for projects
-A short tagline or description of what your project does.
+# {{project_name}}
+{{description}}
 
 ## Project Structure
 ```
-project-name/
-|- src/                # Source code
-|- tests/              # Unit tests
+{{project_name}}/
+|- {{source_dir}}/                # Source code
+|- {{test_dir}}/               # Unit tests
 |- pyproject.toml      # Python dependencies and setup
 |- README.md           # Project documentation
 |- config/             # Configuration of environments
@@ -39,7 +54,9 @@ How to report something.
 
 ## License
 Only if need it.
-        ";
+
+Copyright (c) {{year}} {{author}}.
+";
 
 pub const SAMPLE_TEST: &str = "\
 import pytest
@@ -47,7 +64,25 @@ import pytest
 def sample_test():
     # Test something
     pass
-        ";
+";
+
+pub const SAMPLE_CONFTEST: &str = "\
+\"\"\"Shared pytest fixtures, available to every test without importing them.\"\"\"
+
+import pytest
+
+
+@pytest.fixture
+def sample_data():
+    \"\"\"Return a small dataset for the tests.
+
+    Returns
+    -------
+    dict
+        Column names mapped to their values.
+    \"\"\"
+    return {\"A\": [1, 2], \"B\": [3, 4]}
+";
 
 pub const SAMPLE_INIT: &str = "\
 \"\"\"Packages initiator.
@@ -57,8 +92,23 @@ Loads the environment variables
 
 from .env import load_env
 
+{{logging_field}}__version__ = \"{{version}}\"
+
 load_env()
-        ";
+";
+
+pub const SAMPLE_INIT_NO_ENV: &str = "\
+\"\"\"Packages initiator.\"\"\"
+
+{{logging_field}}__version__ = \"{{version}}\"
+";
+
+// PEP 561 marker, its presence is all that matters.
+pub const SAMPLE_PY_TYPED: &str = "";
+
+pub const SAMPLE_PARENT_INIT: &str = "\
+\"\"\"Parent package of the project modules.\"\"\"
+";
 
 pub const SAMPLE_GITIGNORE: &str = "\
 # Python-generated files
@@ -74,7 +124,43 @@ wheels/
 
 # jupyter checkpoints
 **ipynb_checkpoints**
-            ";
+{{gitignore_patterns}}";
+
+/// The patterns `preset` appends to [`SAMPLE_GITIGNORE`], if any.
+pub fn gitignore_patterns(preset: GitignorePreset) -> &'static str {
+    match preset {
+        GitignorePreset::Python => "",
+        GitignorePreset::PythonData => GITIGNORE_DATA,
+        GitignorePreset::PythonWeb => GITIGNORE_WEB,
+    }
+}
+
+pub const GITIGNORE_DATA: &str = "
+# Datasets
+data/
+*.csv
+*.parquet
+*.feather
+*.h5
+
+# Models and experiment tracking
+*.pkl
+*.joblib
+*.onnx
+mlruns/
+.dvc/cache/
+";
+
+pub const GITIGNORE_WEB: &str = "
+# Static files and uploads
+staticfiles/
+media/
+
+# Local databases and secrets
+*.sqlite3
+db.sqlite3-journal
+.env
+";
 
 pub const SAMPLE_ENV: &str = "\
 \"\"\"Load environment variables.\"\"\"
@@ -88,18 +174,18 @@ import yaml
 
 def find_config_file(
         possible_names: Iterable[str] = (
-                \"config.yaml\", 
-                \"settings.yaml\", 
-                \"DEV.yaml\")
+                \"config.yaml\",
+                \"settings.yaml\",
+                {{config_names}})
         ) -> Optional[Path]:
     \"\"\"Searcher of configuration file.
-    
-    Start searching in current directory, then goes to the parents until fail or 
+
+    Start searching in current directory, then goes to the parents until fail or
     find the possible file name.
-    
+
     Parameters
     ----------
-    possible_names: Iterable[str], default = (\"config.yaml\", \"settings.yaml\", \"DEV.yaml\")
+    possible_names: Iterable[str], default = (\"config.yaml\", \"settings.yaml\", {{config_names}})
         Possible names of YAML configuration file.
 
     Returns
@@ -118,7 +204,7 @@ def find_config_file(
 
 def load_env(path: Optional[str | Path] = None):
     \"\"\"Load the environment variables from a YAML file.
-    
+
     Parameters
     ----------
     path: Optional[str | Path].
@@ -134,18 +220,18 @@ def load_env(path: Optional[str | Path] = None):
     # Load yaml file from the project root
     with open(path, \"r\") as f:
         config = yaml.safe_load(f)
-        
-    # Set environmetn variables
+
+    # Set environment variables
     for mk in config:
         for k in config[mk]:
             os.environ[k] = config[mk][k]
-            ";
+";
 
 pub const SAMPLE_DB: &str = "\
 \"\"\"Databases connections.
 
 This module provides functionalities to build secure conecctions to databases.
-Currently, only supports Oracle and configuring the secrets with environment 
+Currently, only supports Oracle and configuring the secrets with environment
 variables.
 
 Functions
@@ -167,11 +253,11 @@ STD_PRD_DSN = f\"{os.environ.get('DB_DATABASE')}/{os.environ.get('DB_HOST')}\"
 
 def get_engine() -> sqlalchemy.Engine:
     \"\"\"Creates the Orecle connection engine.
-        
-    This functions build the connection to Oracle database using `oracledb` as 
+
+    This functions build the connection to Oracle database using `oracledb` as
     backend for SQLAlchemy.
 
-    This function must be used as interaction gate with the database with the 
+    This function must be used as interaction gate with the database with the
     engine object (`Engine`).
 
     Returns
@@ -190,7 +276,7 @@ def get_engine() -> sqlalchemy.Engine:
 
     Notes
     -----
-    The function ensure `oracledb` instead of legacy `cx_Oracle` as the `cx_Oracle` to 
+    The function ensure `oracledb` instead of legacy `cx_Oracle` as the `cx_Oracle` to
     prevent compatibility problems with `oracle+oracledb` dialect with SQLAlchemy.
 
     Examples
@@ -208,41 +294,168 @@ def get_engine() -> sqlalchemy.Engine:
         connect_args={\"user\": STD_PRD, \"password\": STD_PRD_PASS, \"dsn\": STD_PRD_DSN},
     )
     return engine
-              ";
+";
+
+pub const SAMPLE_DB_POSTGRES: &str = "\
+\"\"\"Databases connections.
+
+This module provides functionalities to build secure connections to a PostgreSQL
+database, configuring the secrets with environment variables.
+
+Functions
+---------
+get_engine
+    Function to create the engine to the database.
+\"\"\"
+
+import os
+
+import sqlalchemy
+
+
+def get_engine() -> sqlalchemy.Engine:
+    \"\"\"Creates the PostgreSQL connection engine.
+
+    This functions build the connection using `psycopg` as backend for SQLAlchemy.
+
+    Returns
+    -------
+    sqlalchemy.Engine
+        Connection engine.
+
+    Raises
+    ------
+    KeyError
+        If a environment variable is missing (`DB_USER`,
+        `DB_PASSWORD`, `DB_DATABASE`, `DB_HOST`).
+    \"\"\"
+    url = sqlalchemy.URL.create(
+        \"postgresql+psycopg\",
+        username=os.environ[\"DB_USER\"],
+        password=os.environ[\"DB_PASSWORD\"],
+        host=os.environ[\"DB_HOST\"],
+        database=os.environ[\"DB_DATABASE\"],
+    )
+    return sqlalchemy.create_engine(url)
+";
+
+pub const SAMPLE_DB_MYSQL: &str = "\
+\"\"\"Databases connections.
+
+This module provides functionalities to build secure connections to a MySQL
+database, configuring the secrets with environment variables.
+
+Functions
+---------
+get_engine
+    Function to create the engine to the database.
+\"\"\"
+
+import os
+
+import sqlalchemy
+
+
+def get_engine() -> sqlalchemy.Engine:
+    \"\"\"Creates the MySQL connection engine.
+
+    This functions build the connection using `pymysql` as backend for SQLAlchemy.
+
+    Returns
+    -------
+    sqlalchemy.Engine
+        Connection engine.
+
+    Raises
+    ------
+    KeyError
+        If a environment variable is missing (`DB_USER`,
+        `DB_PASSWORD`, `DB_DATABASE`, `DB_HOST`).
+    \"\"\"
+    url = sqlalchemy.URL.create(
+        \"mysql+pymysql\",
+        username=os.environ[\"DB_USER\"],
+        password=os.environ[\"DB_PASSWORD\"],
+        host=os.environ[\"DB_HOST\"],
+        database=os.environ[\"DB_DATABASE\"],
+    )
+    return sqlalchemy.create_engine(url)
+";
+
+pub const SAMPLE_DB_SQLITE: &str = "\
+\"\"\"Databases connections.
+
+This module provides functionalities to connect to a SQLite database file,
+configured with the `DB_DATABASE` environment variable.
+
+Functions
+---------
+get_engine
+    Function to create the engine to the database.
+\"\"\"
+
+import os
+
+import sqlalchemy
+
+
+def get_engine() -> sqlalchemy.Engine:
+    \"\"\"Creates the SQLite connection engine.
+
+    The database file is `DB_DATABASE`, or `files/database.db` when it is not set.
+
+    Returns
+    -------
+    sqlalchemy.Engine
+        Connection engine.
+    \"\"\"
+    path = os.environ.get(\"DB_DATABASE\", \"files/database.db\")
+    return sqlalchemy.create_engine(f\"sqlite:///{path}\")
+";
+
+/// The `db.py` template for `database`, if any.
+pub fn database_template(database: Database) -> Option<&'static str> {
+    match database {
+        Database::Oracle => Some(SAMPLE_DB),
+        Database::Postgres => Some(SAMPLE_DB_POSTGRES),
+        Database::MySql => Some(SAMPLE_DB_MYSQL),
+        Database::Sqlite => Some(SAMPLE_DB_SQLITE),
+        Database::None => None,
+    }
+}
 
 pub static SAMPLE_PYPROJECT: &str = "\
 [build-system]
-requires = [\"setuptools >= 70.0\"]
-build-backend = \"setuptools.build_meta\"
-
+{{build_system}}
 [project]
-name = \"{}\"
-version = \"0.1.0\"
+name = \"{{package_name}}\"
+version = \"{{version}}\"
 description = \"Some description of the project.\"
 readme = \"README.md\"
-requires-python = \"==3.14.*\"
+{{license_field}}authors = [{ name = \"{{author}}\" }]
+requires-python = \"=={{python_version}}.*\"
 dependencies = [
-    \"oracledb\",
-    \"sqlalchemy\",
-    \"numpy\",
-    \"polars\",
-    \"plotly\",
-    \"structlog\"
+{{dependencies}}
 ]
 
 # Scripts here
 [project.scripts]
+\"{{command_name}}\" = \"{{package_name}}.main:main\"
 
 # Uv groups dependencies
 [dependency-groups]
 dev = [
-    \"jupyterlab>=4.4.0\",
-    \"pytest\",
-    \"ipywidgets\",
+{{dev_dependencies}}
 ]
 
+{{backend_tool_field}}{{typing_field}}[tool.pytest.ini_options]
+testpaths = [\"{{test_dir}}\"]
+# Also collect the `*_test.py` files and `*_test` functions of the samples.
+python_files = [\"test_*.py\", \"*_test.py\"]
+python_functions = [\"test_*\", \"*_test\"]
+
 [tool.ruff]
-target-version = \"py314\"
+target-version = \"{{python_tag}}\"
 
 [tool.ruff.lint]
 extend-select = [\"SIM\", \"I\", \"D\", \"S\", \"PT\"]
@@ -251,21 +464,136 @@ extend-select = [\"SIM\", \"I\", \"D\", \"S\", \"PT\"]
 convention = \"numpy\"
 
 [tool.ruff.lint.per-file-ignores]
-\"test/*\" = [\"D\", \"s\"]
-                                         ";
+\"{{test_dir}}/*\" = [\"D\", \"s\"]
+";
+
+// The same metadata as `pyproject.toml`, for setuptools versions without PEP 621.
+pub const SAMPLE_SETUP_CFG: &str = "\
+[metadata]
+name = {{package_name}}
+version = {{version}}
+description = Some description of the project.
+long_description = file: README.md
+long_description_content_type = text/markdown
+author = {{author}}
+{{setup_license_field}}
+[options]
+python_requires = =={{python_version}}.*
+{{setup_packages_field}}install_requires =
+{{install_requires}}
+[options.entry_points]
+console_scripts =
+    {{command_name}} = {{package_name}}.main:main
+
+{{setup_find_field}}[tool:pytest]
+testpaths = {{test_dir}}
+python_files = test_*.py *_test.py
+python_functions = test_* *_test
+";
+
+pub const SAMPLE_SETUP_PY: &str = "\
+\"\"\"Entry point of the tools still running `setup.py`, see `setup.cfg`.\"\"\"
+
+from setuptools import setup
+
+setup()
+";
+
+pub const SAMPLE_LOGGING: &str = "\
+\"\"\"Logging setup of the package.
+
+structlog is configured once, when the package is imported, so every module only
+needs `logger = get_logger(__name__)`.
+\"\"\"
+
+import logging
+import os
+from typing import Optional
+
+import structlog
+
+
+def configure_logging(level: Optional[str] = None, json: Optional[bool] = None):
+    \"\"\"Configure the structlog processors.
+
+    Parameters
+    ----------
+    level: Optional[str], default = None
+        Minimum level logged, e.g. \"DEBUG\". If None, read from the `LOG_LEVEL`
+        environment variable, or \"INFO\".
+    json: Optional[bool], default = None
+        Render the logs as JSON lines instead of colored text. If None, true when
+        the `LOG_FORMAT` environment variable is \"json\".
+    \"\"\"
+    if level is None:
+        level = os.environ.get(\"LOG_LEVEL\", \"INFO\")
+    if json is None:
+        json = os.environ.get(\"LOG_FORMAT\", \"\").lower() == \"json\"
+    processors = [
+        structlog.contextvars.merge_contextvars,
+        structlog.processors.add_log_level,
+        structlog.processors.TimeStamper(fmt=\"iso\"),
+        structlog.processors.StackInfoRenderer(),
+        structlog.processors.format_exc_info,
+        structlog.processors.JSONRenderer() if json else structlog.dev.ConsoleRenderer(),
+    ]
+    structlog.configure(
+        processors=processors,
+        wrapper_class=structlog.make_filtering_bound_logger(
+            getattr(logging, level.upper(), logging.INFO)
+        ),
+        cache_logger_on_first_use=True,
+    )
+
+
+def get_logger(name: Optional[str] = None):
+    \"\"\"Get a logger bound to `name`, usually `__name__`.
+
+    Parameters
+    ----------
+    name: Optional[str], default = None
+        Name of the logger, added to every event as `logger`.
+
+    Returns
+    -------
+    structlog.typing.FilteringBoundLogger
+        The configured logger.
+    \"\"\"
+    if name is None:
+        return structlog.get_logger()
+    return structlog.get_logger(logger=name)
+
+
+configure_logging()
+";
 
 pub const SAMPLE_MAIN: &str = "\
 \"\"\"Example of main file with logs.\"\"\"
 
 import structlog
-import polars as pl 
+import polars as pl
 
 # This must be call in every file to log.
 logger = structlog.get_logger()
 
-df = pl.DataFrame({\"A\": [1, 2], \"B\": [3, 4]})
-logger.info(\"Hello world!\", more_than_strings=df)
-        ";
+
+def main():
+    \"\"\"Run the application.\"\"\"
+    df = pl.DataFrame({\"A\": [1, 2], \"B\": [3, 4]})
+    logger.info(\"Hello world!\", more_than_strings=df)
+
+
+if __name__ == \"__main__\":
+    main()
+";
+
+pub const SAMPLE_DUNDER_MAIN: &str = "\
+\"\"\"Entry point of `python -m {{package_name}}`.\"\"\"
+
+from .main import main
+
+main()
+";
 
 pub const SAMPLE_CONFIG: &str = "\
 # Environment variables are splited if categories to make them easier
@@ -274,5 +602,452 @@ DB:
     DB_USER: \"some_user\"
     DB_PASSWORD: \"some_password\"
     DB_HOST: \"some_host\"
-    DB_DATABASE:\"some_service\"
-        ";
+    DB_DATABASE: \"some_service\"
+";
+
+pub const SAMPLE_CI_GITHUB: &str = "\
+name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: actions/setup-python@v5
+        with:
+          python-version: \"{{python_version}}\"
+      - name: Install dependencies
+        run: |
+          python -m pip install --upgrade pip
+          pip install . ruff pytest
+      - name: Lint
+        run: ruff check .
+      - name: Test
+        run: pytest
+";
+
+pub const SAMPLE_DOCKERFILE: &str = "\
+# Build stage: installs the project and its dependencies in a virtual environment.
+FROM python:{{python_version}} AS builder
+WORKDIR /app
+RUN python -m venv /opt/venv
+ENV PATH=\"/opt/venv/bin:$PATH\"
+COPY pyproject.toml README.md ./
+COPY {{source_dir}}/ {{source_dir}}/
+RUN pip install --no-cache-dir .
+
+# Runtime stage: only the virtual environment on a slim image.
+FROM python:{{python_version}}-slim
+WORKDIR /app
+COPY --from=builder /opt/venv /opt/venv
+COPY config/ config/
+ENV PATH=\"/opt/venv/bin:$PATH\"
+CMD [\"python\", \"-m\", \"{{package_name}}.main\"]
+";
+
+pub const SAMPLE_DOCKERIGNORE: &str = "\
+# Virtual environments
+.venv
+
+# Python-generated files
+**/__pycache__
+*.py[oc]
+build/
+dist/
+*.egg-info
+
+# Development only
+.git
+.github
+notebooks/
+files/
+**/.ipynb_checkpoints
+";
+
+// The ruff settings live in `pyproject.toml`, the hooks only pin the version.
+pub const SAMPLE_PRECOMMIT: &str = "\
+repos:
+  - repo: https://github.com/astral-sh/ruff-pre-commit
+    rev: v0.12.0
+    hooks:
+      - id: ruff
+        args: [--fix]
+      - id: ruff-format
+";
+
+pub const SAMPLE_EDITORCONFIG: &str = "\
+root = true
+
+[*]
+charset = utf-8
+end_of_line = lf
+insert_final_newline = true
+trim_trailing_whitespace = true
+
+[*.py]
+indent_style = space
+indent_size = 4
+
+[*.{yml,yaml}]
+indent_style = space
+indent_size = 2
+";
+
+pub const SAMPLE_MKDOCS: &str = "\
+site_name: {{project_name}}
+nav:
+  - Home: index.md
+theme:
+  name: material
+";
+
+pub const SAMPLE_MKDOCS_INDEX: &str = "\
+# {{project_name}}
+
+{{description}}
+
+## Writing the docs
+
+Pages are Markdown files in `docs/`, listed in the `nav` of `mkdocs.yml`.
+Preview them with live reload:
+
+```bash
+uv run mkdocs serve
+```
+";
+
+pub const SAMPLE_SPHINX_CONF: &str = "\
+\"\"\"Sphinx configuration of {{project_name}}.\"\"\"
+
+project = \"{{project_name}}\"
+author = \"{{author}}\"
+copyright = \"{{year}}, {{author}}\"
+release = \"{{version}}\"
+
+# Napoleon reads the numpy style docstrings enforced by ruff.
+extensions = [\"sphinx.ext.autodoc\", \"sphinx.ext.napoleon\"]
+exclude_patterns = [\"_build\"]
+html_theme = \"alabaster\"
+";
+
+pub const SAMPLE_SPHINX_INDEX: &str = "\
+Documentation
+=============
+
+{{description}}
+
+Build these pages with ``uv run sphinx-build docs docs/_build``.
+
+.. toctree::
+   :maxdepth: 2
+   :caption: Contents:
+
+API reference
+-------------
+
+.. automodule:: {{package_name}}.main
+   :members:
+";
+
+/// The config and index templates of `engine`, as `(docs config, docs/index)`.
+/// The mkdocs config sits in the project root, the Sphinx one in `docs/`.
+pub fn doc_templates(engine: DocEngine) -> (&'static str, &'static str) {
+    match engine {
+        DocEngine::MkDocs => (SAMPLE_MKDOCS, SAMPLE_MKDOCS_INDEX),
+        DocEngine::Sphinx => (SAMPLE_SPHINX_CONF, SAMPLE_SPHINX_INDEX),
+    }
+}
+
+/// The config of `runner`, if any: `tox.ini` or `noxfile.py`.
+pub fn test_runner_template(runner: TestRunner) -> Option<&'static str> {
+    match runner {
+        TestRunner::Tox => Some(SAMPLE_TOX),
+        TestRunner::Nox => Some(SAMPLE_NOX),
+        TestRunner::None => None,
+    }
+}
+
+// `{posargs}` is a tox substitution, not a placeholder.
+pub const SAMPLE_TOX: &str = "\
+[tox]
+envlist = {{tox_envs}}
+
+[testenv]
+description = Run the unit tests with pytest
+deps = pytest
+commands = pytest {posargs:{{test_dir}}}
+";
+
+pub const SAMPLE_NOX: &str = "\
+\"\"\"Sessions of nox, run them with `nox`.\"\"\"
+
+import nox
+
+
+@nox.session(python=[{{nox_pythons}}])
+def tests(session):
+    \"\"\"Run the unit tests with pytest.\"\"\"
+    session.install(\".\", \"pytest\")
+    session.run(\"pytest\", \"{{test_dir}}\", *session.posargs)
+";
+
+pub const SAMPLE_CONTRIBUTING: &str = "\
+# Contributing to {{project_name}}
+
+Thanks for taking the time to contribute! Please read the
+[Code of Conduct](CODE_OF_CONDUCT.md) before getting started.
+
+## Reporting issues
+
+Open an issue describing what you expected, what happened instead and the
+steps to reproduce it, including the Python version and operating system.
+
+## Development setup
+
+The project is managed with [uv](https://docs.astral.sh/uv/):
+
+```bash
+uv sync
+uv run pytest
+```
+
+## Pull requests
+
+1. Fork the repository and create a branch from `main`.
+2. Add tests for any change in behavior.
+3. Make sure `uv run ruff check` and `uv run ruff format --check` pass.
+4. Describe the change and link the related issue in the pull request.
+
+Maintainer: {{author}}.
+";
+
+pub const SAMPLE_CODE_OF_CONDUCT: &str = "\
+# Code of Conduct
+
+## Our pledge
+
+We as members, contributors and maintainers of {{project_name}} pledge to make
+participation in our community a harassment-free experience for everyone,
+regardless of age, body size, disability, ethnicity, sex characteristics,
+gender identity and expression, level of experience, education,
+socio-economic status, nationality, personal appearance, race, religion, or
+sexual identity and orientation.
+
+## Our standards
+
+Examples of behavior that contributes to a positive environment:
+
+- Being respectful of differing opinions, viewpoints and experiences.
+- Giving and gracefully accepting constructive feedback.
+- Focusing on what is best for the community.
+
+Examples of unacceptable behavior:
+
+- Harassment, insults or derogatory comments, public or private.
+- Publishing others' private information without their explicit permission.
+- Other conduct which could reasonably be considered inappropriate in a
+  professional setting.
+
+## Enforcement
+
+Instances of abusive, harassing or otherwise unacceptable behavior may be
+reported to the maintainer, {{author}}. All complaints will be reviewed and
+investigated promptly and fairly, and the privacy of the reporter respected.
+
+## Attribution
+
+This Code of Conduct is adapted from the
+[Contributor Covenant](https://www.contributor-covenant.org), version 2.1.
+";
+
+// Recipes must be indented with tabs.
+pub const SAMPLE_MAKEFILE: &str = "\
+.PHONY: install test lint format run
+
+install:
+\tuv sync
+
+test:
+\tuv run pytest {{test_dir}}
+
+lint:
+\tuv run ruff check .
+\tuv run ruff format --check .
+
+format:
+\tuv run ruff format .
+\tuv run ruff check --fix .
+
+run:
+\tuv run python -m {{package_name}}
+";
+
+// The version is the one of `pyproject.toml` and `__version__`.
+pub const SAMPLE_CHANGELOG: &str = "\
+# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [Unreleased]
+
+## [{{version}}] - {{date}}
+
+### Added
+
+- Initial project structure.
+";
+
+pub const SAMPLE_SECURITY: &str = "\
+# Security Policy
+
+## Supported versions
+
+Security fixes are made for the latest release of {{project_name}} only.
+
+## Reporting a vulnerability
+
+Please do not report security vulnerabilities through public issues.
+
+Instead, send an email to {{security_email}} with a description of the issue,
+the steps to reproduce it and the affected versions. You should receive an
+answer within a few days. Once the issue is confirmed, a fix is released as
+soon as possible and you are credited in the release notes, unless you prefer
+otherwise.
+";
+
+/// The `LICENSE` template for `license`, if any.
+pub fn license_template(license: License) -> Option<&'static str> {
+    match license {
+        License::Mit => Some(SAMPLE_LICENSE_MIT),
+        License::Apache2 => Some(SAMPLE_LICENSE_APACHE),
+        License::Bsd3Clause => Some(SAMPLE_LICENSE_BSD3),
+        License::Gpl3 => Some(SAMPLE_LICENSE_GPL3),
+        License::None => None,
+    }
+}
+
+pub const SAMPLE_LICENSE_MIT: &str = "\
+MIT License
+
+Copyright (c) {{year}} {{author}}
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+";
+
+pub const SAMPLE_LICENSE_BSD3: &str = "\
+BSD 3-Clause License
+
+Copyright (c) {{year}}, {{author}}
+
+Redistribution and use in source and binary forms, with or without
+modification, are permitted provided that the following conditions are met:
+
+1. Redistributions of source code must retain the above copyright notice, this
+   list of conditions and the following disclaimer.
+
+2. Redistributions in binary form must reproduce the above copyright notice,
+   this list of conditions and the following disclaimer in the documentation
+   and/or other materials provided with the distribution.
+
+3. Neither the name of the copyright holder nor the names of its
+   contributors may be used to endorse or promote products derived from
+   this software without specific prior written permission.
+
+THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\"
+AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+";
+
+// The Apache and GPL texts are too long to embed, so only their official
+// notices are written, pointing to the full license.
+pub const SAMPLE_LICENSE_APACHE: &str = "\
+Copyright {{year}} {{author}}
+
+Licensed under the Apache License, Version 2.0 (the \"License\");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an \"AS IS\" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+";
+
+pub const SAMPLE_LICENSE_GPL3: &str = "\
+{{project_name}}
+Copyright (C) {{year}} {{author}}
+
+This program is free software: you can redistribute it and/or modify
+it under the terms of the GNU General Public License as published by
+the Free Software Foundation, either version 3 of the License, or
+(at your option) any later version.
+
+This program is distributed in the hope that it will be useful,
+but WITHOUT ANY WARRANTY; without even the implied warranty of
+MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License
+along with this program.  If not, see <https://www.gnu.org/licenses/>.
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yaml_rust2::YamlLoader;
+
+    #[test]
+    fn test_config_is_valid_yaml() {
+        let docs = YamlLoader::load_from_str(SAMPLE_CONFIG).unwrap();
+        let db = &docs[0]["DB"];
+        for (key, value) in [
+            ("DB_USER", "some_user"),
+            ("DB_PASSWORD", "some_password"),
+            ("DB_HOST", "some_host"),
+            ("DB_DATABASE", "some_service"),
+        ] {
+            assert_eq!(db[key].as_str(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_yaml_templates_parse() {
+        for template in [SAMPLE_CI_GITHUB, SAMPLE_PRECOMMIT, SAMPLE_MKDOCS] {
+            assert!(YamlLoader::load_from_str(template).is_ok());
+        }
+    }
+}
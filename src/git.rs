@@ -0,0 +1,41 @@
+//! Git Post-Build Step
+//!
+//! Turns a freshly built project into a git repository with an initial commit,
+//! by running the `git` executable found on the `PATH`. Nothing here is fatal to
+//! a build: every failure is returned as a message for the [`crate::BuildReport`]
+//! warnings, since the project itself was already created.
+use log::debug;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// Runs `git init`, `git add --all` and `git commit -m <message>` in `root`,
+/// stopping at the first failure.
+///
+/// Returns a description of the failure, e.g. when `git` is not installed or
+/// no committer identity is configured.
+pub(crate) fn init_repository(root: &Path, message: &str) -> Result<(), String> {
+    run_git(root, &["init", "--quiet"])?;
+    run_git(root, &["add", "--all"])?;
+    run_git(root, &["commit", "--quiet", "-m", message])
+}
+
+/// Runs `git <args>` in `dir`, turning a missing executable or a failed status
+/// into a message.
+fn run_git(dir: &Path, args: &[&str]) -> Result<(), String> {
+    debug!("Running git {} in {}", args.join(" "), dir.display());
+    let output = match Command::new("git").args(args).current_dir(dir).output() {
+        Ok(output) => output,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            return Err("git is not installed, the repository was not initialized".to_string());
+        }
+        Err(error) => return Err(format!("Could not run git: {error}")),
+    };
+    if output.status.success() {
+        return Ok(());
+    }
+    // The last line of git is the `fatal:` one, the rest is advice.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reason = stderr.trim().lines().last().unwrap_or_default();
+    Err(format!("`git {}` failed: {reason}", args[0]))
+}
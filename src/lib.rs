@@ -9,59 +9,1398 @@
 //! 2. **Directory Creation**: Scaffolds the folder hierarchy.
 //! 3. **File Creation**: Populates the folders with boilerplate (README, TOML, etc.).
 //! 4. **Rollback**: If any step fails after the root directory is created, the library
-//!    attempts to clean up the partial build to leave the filesystem in a clean state.
+//!    removes exactly the directories and files it created, leaving anything that
+//!    existed before the build untouched.
+//!
+//! Progress is reported through the [`log`] crate (`debug` for each step, `warn`
+//! and `error` for failures), and every created path to the writer given to
+//! [`SkeletonOptions::build_with_progress`], so the library never writes to the
+//! console itself, unless asked to with the deprecated
+//! [`SkeletonOptions::verbose`].
+//!
+//! ## Usage
+//! Configure a build with the [`SkeletonOptions`] builder and run it with
+//! [`SkeletonOptions::build`]:
+//!
+//! ```no_run
+//! use python_skeleton::SkeletonOptions;
+//!
+//! let report = SkeletonOptions::new("My-Project", "my_package")
+//!     .docs(true)
+//!     .output_dir("/tmp/projects")
+//!     .build()
+//!     .expect("the project could not be created");
+//! for dir in &report.created_dirs {
+//!     println!("{}", dir.display());
+//! }
+//! ```
+use log::{debug, error, warn};
 use std::env::current_dir;
-use std::fs::{remove_dir, remove_dir_all};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
 
 pub mod dir_builder;
+pub mod file_system;
 pub mod files_builder;
+mod git;
 pub mod validation;
 
-use validation::Case;
+use file_system::{FileSystem, MemoryFileSystem, OsFileSystem};
+use files_builder::TemplateError;
+use validation::{Case, TrainCaseStyle, ValidationOptions};
 
 /// Errors that can occur during the project building process.
-#[derive(Debug, PartialEq)]
+///
+/// Filesystem failures keep the underlying [`io::Error`], so callers can inspect
+/// its [`io::ErrorKind`] or reach it through [`Error::source`].
+#[derive(Debug)]
 pub enum BuildError {
     /// Encountered when a filesystem operation fails (permissions, missing paths, etc.).
-    IOError,
+    IOError(io::Error),
+    /// Encountered when a directory or file of the skeleton can not be created.
+    /// Whatever the build created before is rolled back.
+    PathError {
+        /// Whether the directories or the files were being created.
+        phase: BuildPhase,
+        /// The directory or file that could not be created.
+        path: PathBuf,
+        /// The error of the filesystem.
+        error: io::Error,
+    },
     /// Encountered when a provided name does not match the required naming convention.
     NameError,
+    /// Encountered when the project root already exists and `force` was not set.
+    AlreadyExists(PathBuf),
+    /// Encountered when the output directory is used as the project root, is not
+    /// empty and `force` was not set.
+    NotEmpty(PathBuf),
+    /// Encountered when the output directory, or one of its parents, is a file,
+    /// so the project can not be created there. Carries the file.
+    OutputNotDirectory(PathBuf),
+    /// Encountered when a `..` of the output directory climbs out of the closest
+    /// existing parent through directories that do not exist yet, e.g.
+    /// `missing/../../projects`. Carries the output directory.
+    OutputEscapes(PathBuf),
+    /// Encountered when a path of the [`SkeletonOptions::extra_files`] is empty,
+    /// absolute or uses `..`, so it is not inside the project. Carries the path.
+    InvalidExtraFile(PathBuf),
+    /// Encountered when a boilerplate template can not be rendered.
+    TemplateError(TemplateError),
+    /// Encountered when the Python version is not `major.minor`, e.g. `3.12`.
+    PythonVersionError(String),
+    /// Encountered when a planned path is longer than the
+    /// [`SkeletonOptions::max_path_len`], which would fail late on some systems.
+    PathTooLong {
+        /// The longest planned path.
+        path: PathBuf,
+        /// The limit it exceeds, in characters.
+        limit: usize,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::IOError(error) => write!(f, "A filesystem operation failed: {error}"),
+            BuildError::PathError { phase, path, error } => {
+                let what = match phase {
+                    BuildPhase::Directories => "directory",
+                    BuildPhase::Files => "file",
+                };
+                write!(f, "Can not create the {what} {}: {error}", path.display())
+            }
+            BuildError::NameError => write!(f, "The project or package name is not valid"),
+            BuildError::AlreadyExists(path) => {
+                write!(f, "The directory {} already exists", path.display())
+            }
+            BuildError::NotEmpty(path) => {
+                write!(f, "The directory {} is not empty", path.display())
+            }
+            BuildError::OutputNotDirectory(path) => {
+                write!(f, "The output path {} is not a directory", path.display())
+            }
+            BuildError::OutputEscapes(path) => write!(
+                f,
+                "The output path {} climbs out of its existing parent with `..`",
+                path.display()
+            ),
+            BuildError::InvalidExtraFile(path) => {
+                write!(
+                    f,
+                    "The extra file {} is not inside the project",
+                    path.display()
+                )
+            }
+            BuildError::TemplateError(error) => {
+                write!(f, "A template could not be rendered: {error}")
+            }
+            BuildError::PythonVersionError(version) => {
+                write!(f, "The Python version `{version}` is not like `3.12`")
+            }
+            BuildError::PathTooLong { path, limit } => write!(
+                f,
+                "The path {} is longer than {limit} characters",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildError::IOError(error) | BuildError::PathError { error, .. } => Some(error),
+            BuildError::TemplateError(error) => Some(error),
+            BuildError::NameError
+            | BuildError::AlreadyExists(_)
+            | BuildError::NotEmpty(_)
+            | BuildError::OutputNotDirectory(_)
+            | BuildError::OutputEscapes(_)
+            | BuildError::InvalidExtraFile(_)
+            | BuildError::PythonVersionError(_)
+            | BuildError::PathTooLong { .. } => None,
+        }
+    }
+}
+
+/// The step of a build a [`BuildError::PathError`] happened in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildPhase {
+    /// Creating the directories, before any file.
+    Directories,
+    /// Writing the files.
+    Files,
+}
+
+/// Record of everything written to disk by a successful [`build_skeleton`].
+///
+/// Paths are absolute and listed in creation order, so callers can print or
+/// post-process the scaffold. On a dry run it lists what would have been created.
+/// With the `serde` feature the report implements `Serialize`.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuildReport {
+    /// Directories created, starting with the project root.
+    pub created_dirs: Vec<PathBuf>,
+    /// Boilerplate files written inside the project.
+    pub created_files: Vec<PathBuf>,
+    /// Files left untouched because they already existed, with
+    /// [`SkeletonOptions::update`].
+    pub skipped_files: Vec<PathBuf>,
+    /// Problems of optional post-build steps, such as
+    /// [`SkeletonOptions::init_git`], which do not fail the build.
+    pub warnings: Vec<String>,
 }
 
+/// Every directory and file a build would create, without touching the disk.
+///
+/// Paths are relative to the output directory and listed in creation order,
+/// starting with the project root. With the `serde` feature the plan implements
+/// `Serialize`, so editor integrations can emit it as JSON.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SkeletonPlan {
+    /// Directories to create, starting with the project root.
+    pub dirs: Vec<PathBuf>,
+    /// Boilerplate files to write inside the project.
+    pub files: Vec<PathBuf>,
+}
+
+/// Like a [`SkeletonPlan`], with the rendered content of every file, as returned
+/// by [`SkeletonOptions::preview`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SkeletonPreview {
+    /// Directories to create, starting with the project root.
+    pub dirs: Vec<PathBuf>,
+    /// Boilerplate files to write inside the project, with their content.
+    pub files: Vec<(PathBuf, String)>,
+}
+
+/// Where the package lives inside the project root.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// `<root>/src/<package>/`: the package is only importable once installed.
+    #[default]
+    SrcLayout,
+    /// `<root>/<package>/`: the package sits next to the project files.
+    FlatLayout,
+}
+
+/// The license of the generated project, written to `LICENSE` and declared in
+/// `pyproject.toml`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum License {
+    /// The MIT license.
+    Mit,
+    /// The Apache License 2.0.
+    Apache2,
+    /// The BSD 3-Clause "New" or "Revised" license.
+    Bsd3Clause,
+    /// The GNU General Public License v3.0.
+    Gpl3,
+    /// No license: neither `LICENSE` nor the `license` field are written.
+    #[default]
+    None,
+}
+
+impl License {
+    /// The SPDX identifier of the license, `None` for [`License::None`].
+    pub fn spdx_id(self) -> Option<&'static str> {
+        match self {
+            License::Mit => Some("MIT"),
+            License::Apache2 => Some("Apache-2.0"),
+            License::Bsd3Clause => Some("BSD-3-Clause"),
+            License::Gpl3 => Some("GPL-3.0-or-later"),
+            License::None => None,
+        }
+    }
+}
+
+/// The database the generated `db.py` module connects to through SQLAlchemy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Database {
+    /// Oracle, through `oracledb`.
+    Oracle,
+    /// PostgreSQL, through `psycopg`.
+    Postgres,
+    /// MySQL, through `pymysql`.
+    MySql,
+    /// SQLite, through the standard library.
+    Sqlite,
+    /// No database: `db.py` is not written.
+    #[default]
+    None,
+}
+
+/// The PEP 517 build backend declared in the `[build-system]` of `pyproject.toml`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BuildBackend {
+    /// `setuptools`, which finds the package on its own.
+    #[default]
+    Setuptools,
+    /// `hatchling`, from the Hatch project.
+    Hatchling,
+    /// `poetry-core`, from Poetry 2.
+    Poetry,
+    /// `pdm-backend`, from PDM.
+    Pdm,
+}
+
+/// How the package metadata is handed to the build tools.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PackagingStyle {
+    /// Only `pyproject.toml`, with the chosen [`BuildBackend`].
+    #[default]
+    Modern,
+    /// Also a `setup.cfg` with the metadata and a `setup.py` shim, for tools that
+    /// can not read `pyproject.toml`. The backend is always
+    /// [`BuildBackend::Setuptools`].
+    Legacy,
+}
+
+/// The documentation tool configured in `docs/` when docs are included.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DocEngine {
+    /// `mkdocs.yml` with the Material theme and a Markdown `docs/index.md`.
+    #[default]
+    MkDocs,
+    /// `docs/conf.py` and a reStructuredText `docs/index.rst` for Sphinx.
+    Sphinx,
+}
+
+/// The tool running the unit tests against every supported Python version.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TestRunner {
+    /// A `tox.ini` with one `py3XY` environment per version.
+    Tox,
+    /// A `noxfile.py` with a `tests` session parametrized by version.
+    Nox,
+    /// No runner: the tests only run with the targeted version.
+    #[default]
+    None,
+}
+
+/// The patterns written to `.gitignore`, on top of the Python ones.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GitignorePreset {
+    /// Bytecode, builds, virtual environments and notebook checkpoints.
+    #[default]
+    Python,
+    /// Also datasets, model files and the stores of MLflow and DVC.
+    PythonData,
+    /// Also collected static files, uploads and local databases.
+    PythonWeb,
+}
+
+/// What to do with a boilerplate file that already exists on disk.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// Fail with [`io::ErrorKind::AlreadyExists`].
+    Error,
+    /// Keep the existing file and log it.
+    Skip,
+    /// Replace the existing file.
+    #[default]
+    Overwrite,
+}
+
+/// Configuration of a project skeleton, built step by step.
+///
+/// Only the project and package names are required; every other option has a
+/// sensible default and is set through a chained method, so new options can be
+/// added without breaking existing callers. Call [`SkeletonOptions::build`] to
+/// generate the project.
+///
+/// # Examples
+///
+/// ```no_run
+/// use python_skeleton::{BuildError, SkeletonOptions};
+///
+/// fn main() -> Result<(), BuildError> {
+///     let report = SkeletonOptions::new("My-Project", "my_package")
+///         .docs(true)
+///         .output_dir("./projects")
+///         .build()?;
+///     println!("Created {} files", report.created_files.len());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkeletonOptions {
+    project_name: String,
+    package_name: String,
+    verbose: bool,
+    include_doc_dir: bool,
+    doc_engine: DocEngine,
+    dry_run: bool,
+    output_dir: Option<PathBuf>,
+    force: bool,
+    use_current_as_root: bool,
+    update: bool,
+    max_path_len: usize,
+    train_case_style: TrainCaseStyle,
+    layout: Layout,
+    namespace_package: bool,
+    tests_dir: bool,
+    description: String,
+    author: String,
+    version: String,
+    license: License,
+    include_ci: bool,
+    include_docker: bool,
+    include_precommit: bool,
+    include_editorconfig: bool,
+    include_community_files: bool,
+    include_changelog: bool,
+    include_security: bool,
+    security_email: String,
+    include_makefile: bool,
+    include_conftest: bool,
+    python_version: String,
+    include_python_version_file: bool,
+    include_version_file: bool,
+    include_requirements_txt: bool,
+    init_git: bool,
+    git_message: String,
+    database: Database,
+    test_runner: TestRunner,
+    gitignore_preset: GitignorePreset,
+    backend: BuildBackend,
+    packaging: PackagingStyle,
+    include_env: bool,
+    environments: Vec<String>,
+    dependencies: Vec<String>,
+    dev_dependencies: Vec<String>,
+    overwrite: OverwritePolicy,
+    include_typing: bool,
+    include_logging: bool,
+    template_dir: Option<PathBuf>,
+    template_overrides_dir: Option<PathBuf>,
+    extra_files: Vec<(PathBuf, String)>,
+}
+
+impl SkeletonOptions {
+    /// Starts a configuration for `project_name` (`Train-Case`) with the package
+    /// `package_name` (`snake_case`, or dotted `snake_case` segments such as
+    /// `acme.client` for nested packages). Names are validated when building.
+    ///
+    /// An empty `package_name` is derived from the project name, so `My-Cool-Lib`
+    /// gets the package `my_cool_lib`.
+    pub fn new(project_name: impl Into<String>, package_name: impl Into<String>) -> Self {
+        SkeletonOptions {
+            project_name: project_name.into(),
+            package_name: package_name.into(),
+            verbose: false,
+            include_doc_dir: false,
+            doc_engine: DocEngine::default(),
+            dry_run: false,
+            output_dir: None,
+            force: false,
+            use_current_as_root: false,
+            update: false,
+            max_path_len: 255,
+            train_case_style: TrainCaseStyle::default(),
+            layout: Layout::default(),
+            namespace_package: false,
+            tests_dir: false,
+            description: "A short tagline or description of what your project does.".to_string(),
+            author: "Your Name".to_string(),
+            version: "0.1.0".to_string(),
+            license: License::default(),
+            include_ci: false,
+            include_docker: false,
+            include_precommit: false,
+            include_editorconfig: false,
+            include_community_files: false,
+            include_changelog: false,
+            include_security: false,
+            security_email: "security@example.com".to_string(),
+            include_makefile: false,
+            include_conftest: false,
+            python_version: files_builder::files_content::PYTHON_VERSION.to_string(),
+            include_python_version_file: false,
+            include_version_file: false,
+            include_requirements_txt: false,
+            init_git: false,
+            git_message: "Initial commit".to_string(),
+            database: Database::default(),
+            test_runner: TestRunner::default(),
+            gitignore_preset: GitignorePreset::default(),
+            backend: BuildBackend::default(),
+            packaging: PackagingStyle::default(),
+            include_env: true,
+            environments: vec!["DEV".to_string()],
+            dependencies: ["numpy", "polars", "plotly", "structlog"]
+                .map(String::from)
+                .to_vec(),
+            dev_dependencies: ["jupyterlab>=4.4.0", "pytest", "ipywidgets"]
+                .map(String::from)
+                .to_vec(),
+            overwrite: OverwritePolicy::default(),
+            include_typing: false,
+            include_logging: false,
+            template_dir: None,
+            template_overrides_dir: None,
+            extra_files: Vec::new(),
+        }
+    }
+
+    /// If true, [`SkeletonOptions::build`] writes its progress to the standard
+    /// output, as [`SkeletonOptions::build_with_progress`] does to any writer.
+    /// The validation steps are logged at `debug` level either way.
+    #[deprecated(note = "use `build_with_progress`, or enable `debug` logs")]
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Whether to include a `docs/` directory in the structure, with a starter
+    /// configuration of the [`SkeletonOptions::doc_engine`].
+    pub fn docs(mut self, include_doc_dir: bool) -> Self {
+        self.include_doc_dir = include_doc_dir;
+        self
+    }
+
+    /// The documentation tool configured when [`SkeletonOptions::docs`] is set,
+    /// which is also added to the dev dependencies. Defaults to
+    /// [`DocEngine::MkDocs`].
+    pub fn doc_engine(mut self, doc_engine: DocEngine) -> Self {
+        self.doc_engine = doc_engine;
+        self
+    }
+
+    /// If true, only validates the names and plans the paths; nothing is written
+    /// to disk and the returned report lists the planned paths, each one also
+    /// written to the progress of [`SkeletonOptions::build_with_progress`].
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The directory where the project root is created. Defaults to the current
+    /// working directory.
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(output_dir.into());
+        self
+    }
+
+    /// If true, builds even when the project root already exists, instead of
+    /// failing with [`BuildError::AlreadyExists`].
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// If true, the output directory itself is the project root, e.g. a freshly
+    /// cloned repository, instead of the parent of a new `<project_name>/` one.
+    /// It must exist and be empty, unless [`SkeletonOptions::force`] is set.
+    pub fn use_current_as_root(mut self, use_current_as_root: bool) -> Self {
+        self.use_current_as_root = use_current_as_root;
+        self
+    }
+
+    /// The longest path, in characters, a build may create, output directory
+    /// included. Checked before anything is written, since long paths fail late,
+    /// e.g. past the 260 characters of Windows. Defaults to 255.
+    pub fn max_path_len(mut self, max_path_len: usize) -> Self {
+        self.max_path_len = max_path_len;
+        self
+    }
+
+    /// How the project name is normalized to Train-Case, see [`TrainCaseStyle`].
+    /// Defaults to [`TrainCaseStyle::Capitalize`], so `my-API-client` becomes
+    /// `My-Api-Client`.
+    pub fn train_case_style(mut self, train_case_style: TrainCaseStyle) -> Self {
+        self.train_case_style = train_case_style;
+        self
+    }
+
+    /// Where to place the package, see [`Layout`]. Defaults to [`Layout::SrcLayout`].
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Whether the parents of a dotted package (`acme` in `acme.client`) are
+    /// PEP 420 implicit namespace packages, written without `__init__.py`. The
+    /// leaf package keeps its own. No effect on undotted packages.
+    pub fn namespace_package(mut self, namespace_package: bool) -> Self {
+        self.namespace_package = namespace_package;
+        self
+    }
+
+    /// If true, the unit tests go in `tests/`, the usual Python convention, instead
+    /// of the historical `test/`.
+    pub fn tests_dir(mut self, tests_dir: bool) -> Self {
+        self.tests_dir = tests_dir;
+        self
+    }
+
+    /// A one-line description, written under the title of the README.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// The author written into the templates, `Your Name` by default.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    /// The initial version of the project, `0.1.0` by default.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// The license of the project, see [`License`]. Defaults to [`License::None`].
+    pub fn license(mut self, license: License) -> Self {
+        self.license = license;
+        self
+    }
+
+    /// Whether to write a GitHub Actions workflow, `.github/workflows/ci.yml`,
+    /// running `ruff` and `pytest`.
+    pub fn ci(mut self, include_ci: bool) -> Self {
+        self.include_ci = include_ci;
+        self
+    }
+
+    /// Whether to write a multi-stage `Dockerfile` and its `.dockerignore`.
+    pub fn docker(mut self, include_docker: bool) -> Self {
+        self.include_docker = include_docker;
+        self
+    }
+
+    /// Whether to write a `.pre-commit-config.yaml` running the `ruff` linter and
+    /// formatter, configured by the generated `pyproject.toml`.
+    pub fn precommit(mut self, include_precommit: bool) -> Self {
+        self.include_precommit = include_precommit;
+        self
+    }
+
+    /// Whether to write an `.editorconfig` with the indentation of Python and YAML
+    /// files.
+    pub fn editorconfig(mut self, include_editorconfig: bool) -> Self {
+        self.include_editorconfig = include_editorconfig;
+        self
+    }
+
+    /// Whether to write a `CONTRIBUTING.md` and a `CODE_OF_CONDUCT.md` next to
+    /// the README, for open-source projects.
+    pub fn community_files(mut self, include_community_files: bool) -> Self {
+        self.include_community_files = include_community_files;
+        self
+    }
+
+    /// Whether to write a `CHANGELOG.md` in the Keep a Changelog format, with an
+    /// `[Unreleased]` section and a first entry for the [`SkeletonOptions::version`].
+    pub fn changelog(mut self, include_changelog: bool) -> Self {
+        self.include_changelog = include_changelog;
+        self
+    }
+
+    /// Whether to write a `SECURITY.md` telling how to report a vulnerability
+    /// privately, to the [`SkeletonOptions::security_email`].
+    pub fn security(mut self, include_security: bool) -> Self {
+        self.include_security = include_security;
+        self
+    }
+
+    /// The address of the `SECURITY.md` vulnerability reports. Defaults to
+    /// `security@example.com`.
+    pub fn security_email(mut self, security_email: impl Into<String>) -> Self {
+        self.security_email = security_email.into();
+        self
+    }
+
+    /// Whether to write a `Makefile` with `install`, `test`, `lint`, `format` and
+    /// `run` targets calling `uv`, `pytest`, `ruff` and `python -m <package>`.
+    pub fn makefile(mut self, include_makefile: bool) -> Self {
+        self.include_makefile = include_makefile;
+        self
+    }
+
+    /// Whether to write a `conftest.py` with an example fixture in the unit tests
+    /// directory. The pytest settings of `pyproject.toml` are always written.
+    pub fn conftest(mut self, include_conftest: bool) -> Self {
+        self.include_conftest = include_conftest;
+        self
+    }
+
+    /// The Python version the project targets, as `major.minor`. It is pinned in
+    /// `requires-python` and used by the ruff `target-version`, the CI workflow,
+    /// the Dockerfile, mypy and the `.python-version` file. Defaults to
+    /// [`files_builder::files_content::PYTHON_VERSION`]. The format is checked
+    /// when building.
+    pub fn python_version(mut self, python_version: impl Into<String>) -> Self {
+        self.python_version = python_version.into();
+        self
+    }
+
+    /// Whether to write a `.python-version` file with the
+    /// [`SkeletonOptions::python_version`], for pyenv and uv.
+    pub fn python_version_file(mut self, include_python_version_file: bool) -> Self {
+        self.include_python_version_file = include_python_version_file;
+        self
+    }
+
+    /// Whether to write a `VERSION` file with the [`SkeletonOptions::version`],
+    /// the same one as `pyproject.toml` and the `__version__` of the package.
+    pub fn version_file(mut self, include_version_file: bool) -> Self {
+        self.include_version_file = include_version_file;
+        self
+    }
+
+    /// Whether to write a `requirements.txt` with the runtime dependencies of
+    /// `pyproject.toml`, one per line, for tools that still read it.
+    pub fn requirements_txt(mut self, include_requirements_txt: bool) -> Self {
+        self.include_requirements_txt = include_requirements_txt;
+        self
+    }
+
+    /// Whether to make the project a git repository with an initial commit of
+    /// every file, once built on disk. The `git` executable is used, and its
+    /// failures, e.g. when it is not installed, end in the
+    /// [`BuildReport::warnings`] instead of failing the build. Skipped on dry
+    /// runs and by [`SkeletonOptions::build_in`].
+    pub fn init_git(mut self, init_git: bool) -> Self {
+        self.init_git = init_git;
+        self
+    }
+
+    /// The message of the initial commit of [`SkeletonOptions::init_git`].
+    /// Defaults to `Initial commit`.
+    pub fn git_message(mut self, git_message: impl Into<String>) -> Self {
+        self.git_message = git_message.into();
+        self
+    }
+
+    /// The database `db.py` connects to, adding its driver to the dependencies.
+    /// Defaults to [`Database::None`], which leaves the module out.
+    pub fn database(mut self, database: Database) -> Self {
+        self.database = database;
+        self
+    }
+
+    /// The runner testing every Python version from the
+    /// [`SkeletonOptions::python_version`] up to the latest one, added to the dev
+    /// dependencies. Defaults to [`TestRunner::None`], which writes no config.
+    pub fn test_runner(mut self, test_runner: TestRunner) -> Self {
+        self.test_runner = test_runner;
+        self
+    }
+
+    /// The patterns of `.gitignore`. Defaults to [`GitignorePreset::Python`];
+    /// the other presets append the patterns of their kind of project.
+    pub fn gitignore_preset(mut self, gitignore_preset: GitignorePreset) -> Self {
+        self.gitignore_preset = gitignore_preset;
+        self
+    }
+
+    /// Whether to write the `env.py` module, which loads `config/` into the
+    /// environment when the package is imported, and its `pyyaml` dependency.
+    /// Enabled by default.
+    pub fn env(mut self, include_env: bool) -> Self {
+        self.include_env = include_env;
+        self
+    }
+
+    /// The environments getting a `config/<ENV>.yaml` file, which `env.py`
+    /// searches for. Names must be `SCREAMING_SNAKE_CASE` and are uppercased
+    /// when building. Defaults to `DEV`.
+    pub fn environments(
+        mut self,
+        environments: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.environments = environments.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The build backend of `pyproject.toml`, see [`BuildBackend`]. Defaults to
+    /// [`BuildBackend::Setuptools`].
+    pub fn backend(mut self, backend: BuildBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Whether to also write `setup.py` and `setup.cfg`, see [`PackagingStyle`].
+    /// Defaults to [`PackagingStyle::Modern`].
+    pub fn packaging(mut self, packaging: PackagingStyle) -> Self {
+        self.packaging = packaging;
+        self
+    }
+
+    /// The runtime dependencies of `pyproject.toml`, replacing the default
+    /// `numpy`, `polars`, `plotly` and `structlog`. The drivers of the chosen
+    /// [`Database`] and `pyyaml` for `env.py` are still added. Note that the
+    /// generated `main.py` imports `polars` and `structlog`.
+    pub fn dependencies(
+        mut self,
+        dependencies: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.dependencies = dependencies.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The `dev` dependency group of `pyproject.toml`, replacing the default
+    /// `jupyterlab`, `pytest` and `ipywidgets`.
+    pub fn dev_dependencies(
+        mut self,
+        dev_dependencies: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.dev_dependencies = dev_dependencies.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether to ship type hints: writes the PEP 561 `py.typed` marker in the
+    /// package and a `[tool.mypy]` section in `pyproject.toml`.
+    pub fn typing(mut self, include_typing: bool) -> Self {
+        self.include_typing = include_typing;
+        self
+    }
+
+    /// Whether to write a `logging_config.py` module configuring the structlog
+    /// processors, with a `get_logger` helper imported by the package `__init__.py`.
+    pub fn logging(mut self, include_logging: bool) -> Self {
+        self.include_logging = include_logging;
+        self
+    }
+
+    /// If true, completes an existing project: only the missing directories and
+    /// files are created, and the existing ones are left untouched and listed in
+    /// [`BuildReport::skipped_files`]. Unlike [`SkeletonOptions::force`], nothing
+    /// is overwritten, e.g. to add the files of a newer version of the builder.
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// What to do with files that already exist, see [`OverwritePolicy`]. Only
+    /// matters with [`SkeletonOptions::force`], or when calling
+    /// [`files_builder::make_files`] directly. Defaults to
+    /// [`OverwritePolicy::Overwrite`].
+    pub fn overwrite(mut self, overwrite: OverwritePolicy) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// A directory of your own templates, copied into the project root after the
+    /// built-in files, keeping its structure. The names and contents of its
+    /// files may use the same `{{placeholders}}` as the built-in templates, e.g.
+    /// `{{package_name}}_notes.md`, and a file with the path of a built-in one
+    /// replaces it. Files must be UTF-8.
+    pub fn template_dir(mut self, template_dir: impl Into<PathBuf>) -> Self {
+        self.template_dir = Some(template_dir.into());
+        self
+    }
+
+    /// A directory overriding some of the built-in templates, read at build time.
+    /// A file at the path of a built-in one, relative to the project root (e.g.
+    /// `README.md` or `pyproject.toml`), is rendered in its place with the same
+    /// `{{placeholders}}`; the other built-in files keep their embedded template
+    /// and the other files of the directory are ignored. Files must be UTF-8.
+    ///
+    /// Unlike [`SkeletonOptions::template_dir`], which adds every file it holds,
+    /// this only swaps the templates of files the options already generate, so
+    /// a `Dockerfile` override is ignored without [`SkeletonOptions::docker`].
+    /// Like it, the directory is always read from the real disk, even when
+    /// building into another [`FileSystem`] with [`SkeletonOptions::build_in`].
+    pub fn template_overrides_dir(mut self, template_overrides_dir: impl Into<PathBuf>) -> Self {
+        self.template_overrides_dir = Some(template_overrides_dir.into());
+        self
+    }
+
+    /// Files of your own, such as `.tool-versions` or `renovate.json`, written
+    /// with the built-in ones. Paths are relative to the project root, missing
+    /// parents are created, and a file with the path of a built-in one replaces
+    /// it. Contents may use the `{{placeholders}}` of the built-in templates, and
+    /// are otherwise written as given. A path that is empty, absolute or uses
+    /// `..` makes the build fail with [`BuildError::InvalidExtraFile`].
+    pub fn extra_files(
+        mut self,
+        extra_files: impl IntoIterator<Item = (impl Into<PathBuf>, impl Into<String>)>,
+    ) -> Self {
+        self.extra_files = extra_files
+            .into_iter()
+            .map(|(path, content)| (path.into(), content.into()))
+            .collect();
+        self
+    }
+
+    /// The name of the unit tests directory, see [`SkeletonOptions::tests_dir`].
+    pub(crate) fn test_dir_name(&self) -> &'static str {
+        if self.tests_dir { "tests" } else { "test" }
+    }
+
+    /// The directory holding the top-level package, relative to the parent of the
+    /// project root: `<root>/src` or `<root>` depending on the [`Layout`].
+    pub(crate) fn source_dir(&self) -> PathBuf {
+        match self.layout {
+            Layout::SrcLayout => Path::new(&self.project_name).join("src"),
+            Layout::FlatLayout => PathBuf::from(&self.project_name),
+        }
+    }
+
+    /// Rewrites `path`, relative to the parent of the project root, to be relative
+    /// to the output directory. With [`SkeletonOptions::use_current_as_root`] the
+    /// `<root>/` prefix is dropped and the root itself becomes `""`; otherwise
+    /// both are the same.
+    pub(crate) fn relative_to_output(&self, path: PathBuf) -> PathBuf {
+        if !self.use_current_as_root {
+            return path;
+        }
+        match path.strip_prefix(&self.project_name) {
+            Ok(rest) => rest.to_path_buf(),
+            Err(_) => path,
+        }
+    }
+
+    /// Orchestrates the creation of the project skeleton.
+    ///
+    /// It validates the names and coordinates the `dir_builder` and `files_builder`
+    /// modules, returning a [`BuildReport`] with every created path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::NameError`] if:
+    /// * The project name is not valid Train-Case.
+    /// * The package name, or any segment of a dotted one, is not valid snake_case.
+    /// * An environment is not valid SCREAMING_SNAKE_CASE, or is repeated.
+    ///
+    /// Returns [`BuildError::PythonVersionError`] if the
+    /// [`SkeletonOptions::python_version`] is not `major.minor`.
+    ///
+    /// The project and package names may have up to [`MAX_NAME_LEN`] characters,
+    /// longer ones are a [`BuildError::NameError`] too.
+    ///
+    /// Returns [`BuildError::AlreadyExists`] if the project root already exists and
+    /// [`SkeletonOptions::force`] is not set. Nothing is written in that case.
+    /// With [`SkeletonOptions::use_current_as_root`], the output directory must
+    /// exist, or it is a [`BuildError::IOError`], and be empty, or it is a
+    /// [`BuildError::NotEmpty`].
+    ///
+    /// Returns [`BuildError::OutputNotDirectory`] if the output directory, or the
+    /// closest of its parents that exists, is a file. Nothing is written in that
+    /// case. The part of the output directory that exists is resolved by the
+    /// filesystem; in the missing part, a `..` cancels the directory before it,
+    /// so `projects/new/../python` creates `projects/python` and no `new`, and
+    /// one climbing out of the existing parent is a
+    /// [`BuildError::OutputEscapes`].
+    ///
+    /// Returns [`BuildError::PathTooLong`] if a path is longer than the
+    /// [`SkeletonOptions::max_path_len`]. Nothing is written in that case either.
+    ///
+    /// Returns [`BuildError::TemplateError`] if a template uses an undefined
+    /// placeholder. Templates are rendered before anything is written.
+    ///
+    /// Returns [`BuildError::PathError`] with the failing path if directory or
+    /// file creation fails, after rolling back what was created.
+    ///
+    /// Returns [`BuildError::IOError`] if no output directory was set and the
+    /// current working directory cannot be accessed.
+    pub fn build(self) -> Result<BuildReport, BuildError> {
+        if self.verbose {
+            return self.build_with_progress(&mut io::stdout());
+        }
+        self.build_with_progress(&mut io::sink())
+    }
+
+    /// Same as [`SkeletonOptions::build`], writing one line to `progress` for
+    /// every directory and file as soon as it is created, or planned on a dry
+    /// run, e.g. to show it to a user or keep it in a buffer. A failing build
+    /// reports what it created before the rollback. Failing to write `progress`
+    /// does not fail the build.
+    ///
+    /// # Errors
+    ///
+    /// See [`SkeletonOptions::build`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use python_skeleton::SkeletonOptions;
+    ///
+    /// let mut progress = Vec::new();
+    /// SkeletonOptions::new("My-Project", "my_package")
+    ///     .build_with_progress(&mut progress)
+    ///     .unwrap();
+    /// print!("{}", String::from_utf8_lossy(&progress));
+    /// ```
+    pub fn build_with_progress(self, progress: &mut dyn Write) -> Result<BuildReport, BuildError> {
+        let init_git = self.init_git && !self.dry_run;
+        let git_message = self.git_message.clone();
+        let (mut report, root) = self.build_on(&OsFileSystem, progress)?;
+        if init_git {
+            match git::init_repository(&root, &git_message) {
+                Ok(()) => {
+                    let _ = writeln!(progress, "Initialized git repository {}", root.display());
+                }
+                Err(warning) => {
+                    warn!("{warning}");
+                    report.warnings.push(warning);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Same as [`SkeletonOptions::build`], writing the skeleton to `fs` instead of
+    /// the disk, e.g. a [`file_system::MemoryFileSystem`]. The output directory
+    /// still defaults to the current one, so set [`SkeletonOptions::output_dir`]
+    /// to get predictable paths.
+    ///
+    /// # Errors
+    ///
+    /// See [`SkeletonOptions::build`], with the errors of `fs` as
+    /// [`BuildError::IOError`].
+    pub fn build_in(self, fs: &impl FileSystem) -> Result<BuildReport, BuildError> {
+        self.build_in_with_progress(fs, &mut io::sink())
+    }
+
+    /// Same as [`SkeletonOptions::build_in`], writing the progress lines of
+    /// [`SkeletonOptions::build_with_progress`] to `progress`.
+    ///
+    /// # Errors
+    ///
+    /// See [`SkeletonOptions::build_in`].
+    pub fn build_in_with_progress(
+        self,
+        fs: &impl FileSystem,
+        progress: &mut dyn Write,
+    ) -> Result<BuildReport, BuildError> {
+        self.build_on(fs, progress).map(|(report, _)| report)
+    }
+
+    /// Does the work of [`SkeletonOptions::build_in`], also returning the project
+    /// root for the post-build steps.
+    fn build_on(
+        self,
+        fs: &impl FileSystem,
+        progress: &mut dyn Write,
+    ) -> Result<(BuildReport, PathBuf), BuildError> {
+        let options = self.normalized()?;
+        // Get safely the output directory, falling back to the current one.
+        let dir = match &options.output_dir {
+            Some(path) => path.clone(),
+            None => match current_dir() {
+                Ok(path) => path,
+                Err(error) => {
+                    error!("Can not get current directory: {error}");
+                    return Err(BuildError::IOError(error));
+                }
+            },
+        };
+        let dir = resolve_output_dir(fs, &dir)?;
+        // Never touch an existing project unless explicitly asked to.
+        let root = match options.use_current_as_root {
+            true => dir.clone(),
+            false => dir.join(&options.project_name),
+        };
+        if options.use_current_as_root {
+            if !fs.is_dir(&root) {
+                error!("The directory {} does not exist", root.display());
+                return Err(BuildError::IOError(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("The directory {} does not exist", root.display()),
+                )));
+            }
+            if !fs.is_empty_dir(&root) && !options.force && !options.update {
+                error!("The directory {} is not empty", root.display());
+                return Err(BuildError::NotEmpty(root));
+            }
+        } else if fs.exists(&root) && !options.force && !options.update {
+            error!("The directory {} already exists", root.display());
+            return Err(BuildError::AlreadyExists(root));
+        }
+        // Render every template before touching the filesystem.
+        let (dirs, files) = options.entries()?;
+        // Refuse paths the filesystem may not take, before writing any.
+        let longest = dirs
+            .iter()
+            .chain(files.iter().map(|(file_name, _)| file_name))
+            .map(|name| dir.join(name))
+            .max_by_key(|path| path.to_string_lossy().chars().count());
+        if let Some(path) = longest
+            && path.to_string_lossy().chars().count() > options.max_path_len
+        {
+            error!("The path {} is too long", path.display());
+            return Err(BuildError::PathTooLong {
+                path,
+                limit: options.max_path_len,
+            });
+        }
+        // Keep what exists when updating, only the missing files are written.
+        let (kept, files): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .partition(|(file_name, _)| options.update && fs.exists(&dir.join(file_name)));
+        let skipped_files: Vec<_> = kept
+            .iter()
+            .map(|(file_name, _)| dir.join(file_name))
+            .collect();
+        for file in &skipped_files {
+            let _ = writeln!(progress, "Kept existing file {}", file.display());
+        }
+        // Plan the paths without touching the filesystem.
+        if options.dry_run {
+            let report = BuildReport {
+                created_dirs: dirs
+                    .iter()
+                    .map(|dir_name| dir.join(dir_name))
+                    .filter(|path| !(options.update && fs.is_dir(path)))
+                    .collect(),
+                created_files: files
+                    .iter()
+                    .map(|(file_name, _)| dir.join(file_name))
+                    .collect(),
+                skipped_files,
+                warnings: Vec::new(),
+            };
+            for path in report.created_dirs.iter().chain(&report.created_files) {
+                let _ = writeln!(progress, "Would create {}", path.display());
+            }
+            return Ok((report, root));
+        }
+        // Make directories safely, delete all the created is error.
+        let mut created_dirs = Vec::new();
+        if let Err((path, error)) =
+            dir_builder::create_dirs(fs, &dir, &dirs, &mut created_dirs, progress)
+        {
+            error!(
+                "There was a prblem creating the directory {}: {error}",
+                path.display()
+            );
+            warn!("Falling back from directories creation");
+            rollback(fs, &[], &created_dirs);
+            return Err(BuildError::PathError {
+                phase: BuildPhase::Directories,
+                path,
+                error,
+            });
+        }
+        // Make the files safele, remove directories and files if an error.
+        let mut created_files = Vec::new();
+        if let Err((path, error)) = files_builder::create_files(
+            fs,
+            &dir,
+            &files,
+            options.overwrite,
+            &mut created_files,
+            progress,
+        ) {
+            error!(
+                "There was a problem creating the file {}. {error}",
+                path.display()
+            );
+            warn!("Falling back from files creation");
+            rollback(fs, &created_files, &created_dirs);
+            return Err(BuildError::PathError {
+                phase: BuildPhase::Files,
+                path,
+                error,
+            });
+        }
+        let report = BuildReport {
+            created_dirs,
+            created_files,
+            skipped_files,
+            warnings: Vec::new(),
+        };
+        Ok((report, root))
+    }
+
+    /// Lists every directory and file [`SkeletonOptions::build`] would create,
+    /// relative to the output directory. Nothing is written, and neither the
+    /// output directory nor an existing project root is checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::NameError`] and [`BuildError::TemplateError`] like
+    /// [`SkeletonOptions::build`], and [`BuildError::IOError`] if the
+    /// [`SkeletonOptions::template_dir`] can not be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use python_skeleton::SkeletonOptions;
+    /// use std::path::Path;
+    ///
+    /// let plan = SkeletonOptions::new("My-Project", "my_package").plan().unwrap();
+    /// assert_eq!(plan.dirs[0], Path::new("My-Project"));
+    /// assert!(plan.files.contains(&Path::new("My-Project/pyproject.toml").to_path_buf()));
+    /// ```
+    pub fn plan(&self) -> Result<SkeletonPlan, BuildError> {
+        let preview = self.preview()?;
+        Ok(SkeletonPlan {
+            dirs: preview.dirs,
+            files: preview
+                .files
+                .into_iter()
+                .map(|(file_name, _)| file_name)
+                .collect(),
+        })
+    }
+
+    /// Same as [`SkeletonOptions::plan`], also rendering the content of every
+    /// file, e.g. to show a preview or to assert on a template.
+    ///
+    /// # Errors
+    ///
+    /// See [`SkeletonOptions::plan`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use python_skeleton::SkeletonOptions;
+    /// use std::path::Path;
+    ///
+    /// let preview = SkeletonOptions::new("My-Project", "my_package")
+    ///     .version("1.2.0")
+    ///     .preview()
+    ///     .unwrap();
+    /// for dir in &preview.dirs {
+    ///     println!("{}/", dir.display());
+    /// }
+    /// let (_, init) = preview
+    ///     .files
+    ///     .iter()
+    ///     .find(|(file, _)| file == Path::new("My-Project/src/my_package/__init__.py"))
+    ///     .unwrap();
+    /// assert!(init.contains("__version__ = \"1.2.0\""));
+    /// ```
+    pub fn preview(&self) -> Result<SkeletonPreview, BuildError> {
+        let options = self.clone().normalized()?;
+        let (dirs, files) = options.entries()?;
+        Ok(SkeletonPreview { dirs, files })
+    }
+
+    /// The directories and rendered files of the skeleton, relative to the output
+    /// directory. Files of the [`SkeletonOptions::template_dir`] replace the
+    /// built-in ones with the same path, and their directories are added after
+    /// the built-in ones.
+    fn entries(&self) -> Result<Entries, BuildError> {
+        let mut dirs = dir_builder::get_dirs(self);
+        let mut files = match files_builder::get_files(self) {
+            Ok(files) => files,
+            Err(error) => {
+                error!("There was a problem rendering the templates: {error}");
+                return Err(BuildError::TemplateError(error));
+            }
+        };
+        let template_files = match files_builder::get_template_dir_files(self) {
+            Ok(template_files) => template_files,
+            Err(error) => {
+                error!("There was a problem with the template directory: {error}");
+                return Err(error);
+            }
+        };
+        for (file_name, content) in template_files {
+            // Parents first, so every directory exists before its children.
+            let parents: Vec<_> = file_name.ancestors().skip(1).collect();
+            for parent in parents.into_iter().rev() {
+                if !parent.as_os_str().is_empty() && !dirs.iter().any(|dir| dir == parent) {
+                    dirs.push(parent.to_path_buf());
+                }
+            }
+            match files.iter_mut().find(|(name, _)| *name == file_name) {
+                Some(file) => file.1 = content,
+                None => files.push((file_name, content)),
+            }
+        }
+        Ok((dirs, files))
+    }
+
+    /// Validates the names and returns the options with the normalized ones.
+    /// From there on, the builders only see the normalized names.
+    fn normalized(self) -> Result<SkeletonOptions, BuildError> {
+        // Check the Python version, it ends up in several files.
+        let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        match self.python_version.split_once('.') {
+            Some((major, minor)) if is_number(major) && is_number(minor) => {}
+            _ => {
+                error!("The Python version `{}` is not valid", self.python_version);
+                return Err(BuildError::PythonVersionError(self.python_version));
+            }
+        }
+        // Long names only end in paths too long for the filesystem.
+        let too_long = |name: &str| name.chars().count() > MAX_NAME_LEN;
+        if too_long(&self.project_name) || too_long(&self.package_name) {
+            error!("The names can not be longer than {MAX_NAME_LEN} characters");
+            return Err(BuildError::NameError);
+        }
+        // Check project name.
+        debug!("Validating `{}` as Train-Case", self.project_name);
+        let train_case = ValidationOptions {
+            train_case: self.train_case_style,
+            ..ValidationOptions::default()
+        };
+        let project_name =
+            match validation::check_name_with(&self.project_name, Case::TrainCase, &train_case) {
+                Ok(project_name) => project_name,
+                Err(error) => {
+                    error!("The name have an error: {error}");
+                    return Err(BuildError::NameError);
+                }
+            };
+        // Derive the package name when none was given.
+        let package_name = if self.package_name.is_empty() {
+            debug!("Deriving the package name from `{project_name}`");
+            match validation::convert_case(&project_name, Case::TrainCase, Case::SnakeCase) {
+                Ok(package_name) => package_name,
+                Err(error) => {
+                    error!("The package name can not be derived: {error}");
+                    return Err(BuildError::NameError);
+                }
+            }
+        } else {
+            self.package_name
+        };
+        // Check package name, derived ones too.
+        debug!("Validating `{package_name}` as snake_case");
+        // Every segment of a dotted package (`acme.client`) must be snake_case.
+        let mut segments = Vec::new();
+        for segment in package_name.split('.') {
+            match validation::check_name(segment, Case::SnakeCase) {
+                Ok(segment) => segments.push(segment),
+                Err(error) => {
+                    error!("The name have an error: {error}");
+                    return Err(BuildError::NameError);
+                }
+            }
+        }
+        // Check the environments, each one names a config file.
+        let mut environments: Vec<String> = Vec::new();
+        for environment in &self.environments {
+            debug!("Validating `{environment}` as SCREAMING_SNAKE_CASE");
+            match validation::check_name(environment, Case::ScreamingSnakeCase) {
+                Ok(environment) if environments.contains(&environment) => {
+                    error!("The environment {environment} is repeated");
+                    return Err(BuildError::NameError);
+                }
+                Ok(environment) => environments.push(environment),
+                Err(error) => {
+                    error!("The environment name have an error: {error}");
+                    return Err(BuildError::NameError);
+                }
+            }
+        }
+        // Extra files must stay inside the project.
+        for (path, _) in &self.extra_files {
+            let inside = path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+            if !inside || path.as_os_str().is_empty() {
+                error!(
+                    "The extra file {} is not inside the project",
+                    path.display()
+                );
+                return Err(BuildError::InvalidExtraFile(path.clone()));
+            }
+        }
+        Ok(SkeletonOptions {
+            project_name,
+            package_name: segments.join("."),
+            environments,
+            ..self
+        })
+    }
+}
+
+/// The longest project or package name accepted, in characters.
+pub const MAX_NAME_LEN: usize = 100;
+
+/// The directory names and `(file_path, file_content)` tuples of a skeleton.
+type Entries = (Vec<PathBuf>, Vec<(PathBuf, String)>);
+
 /// Orchestrates the creation of a new project skeleton.
 ///
-/// This is the primary function of the library. It validates the inputs and coordinates
-/// the `dir_builder` and `files_builder` modules.
+/// This is a thin wrapper around [`SkeletonOptions`], kept for callers that prefer
+/// positional arguments. It validates the inputs and coordinates the `dir_builder`
+/// and `files_builder` modules, returning a [`BuildReport`] with every created path.
 ///
 /// # Arguments
 ///
 /// * `project_name` - The name of the root directory (must be `Train-Case`).
-/// * `pkg_name` - The name of the internal package (must be `snake_case`).
-/// * `verbose` - If true, logs progress and validation steps to the console.
+/// * `pkg_name` - The name of the internal package (must be `snake_case`). When
+///   empty, it is derived from `project_name`, e.g. `My-Cool-Lib` gives `my_cool_lib`.
+/// * `verbose` - If true, writes every created directory and file to `progress`,
+///   see [`SkeletonOptions::build_with_progress`].
 /// * `include_doc_dir` - Whether to include a `docs/` directory in the structure.
+/// * `dry_run` - If true, only validates the names and plans the paths; nothing is
+///   written to disk and the returned report lists the planned paths.
+/// * `output_dir` - The directory where the project root is created. Defaults to the
+///   current working directory when `None`.
+/// * `progress` - Where the verbose output goes, e.g. [`io::stdout`].
 ///
 /// # Errors
 ///
-/// Returns [`BuildError::NameError`] if:
-/// * `project_name` is not valid Train-Case.
-/// * `pkg_name` is not valid snake_case.
-///
-/// Returns [`BuildError::IOError`] if:
-/// * The current working directory cannot be accessed.
-/// * Directory or file creation fails.
+/// See [`SkeletonOptions::build`].
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use python_skeleton::{build_skeleton, BuildError};
+/// use std::io;
 ///
 /// fn main() -> Result<(), BuildError> {
-///     build_skeleton(
+///     let report = build_skeleton(
 ///         "my-awesome-project".to_string(),
 ///         "my_package".to_string(),
 ///         true,
-///         true
+///         true,
+///         false,
+///         None,
+///         &mut io::stdout(),
 ///     )?;
+///     for file in report.created_files {
+///         println!("{}", file.display());
+///     }
 ///     Ok(())
 /// }
 /// ```
@@ -70,70 +1409,864 @@ pub fn build_skeleton(
     pkg_name: String,
     verbose: bool,
     include_doc_dir: bool,
-) -> Result<(), BuildError> {
-    // Check project name.
+    dry_run: bool,
+    output_dir: Option<PathBuf>,
+    progress: &mut dyn Write,
+) -> Result<BuildReport, BuildError> {
+    let mut options = SkeletonOptions::new(project_name, pkg_name)
+        .docs(include_doc_dir)
+        .dry_run(dry_run);
+    options.output_dir = output_dir;
     if verbose {
-        println!("Validating `{}` as Train-Case", project_name);
+        return options.build_with_progress(progress);
     }
-    let project_name = match validation::check_name(project_name, Case::TrainCase) {
-        Ok(project_name) => project_name,
-        Err(error) => {
-            eprintln!("The name have an error: {error}");
-            return Err(BuildError::NameError);
-        }
-    };
-    // Check package name.
-    if verbose {
-        println!("Validating `{}` as snake_case", pkg_name);
+    options.build()
+}
+
+/// Builds the skeleton in memory and streams it as a zip archive into `writer`,
+/// without touching the disk. Entries are relative to the archive root, starting
+/// with the `<project_name>/` directory, so a web service can return the archive
+/// as a download.
+///
+/// The output directory and [`SkeletonOptions::force`] of `options` are ignored.
+/// With [`SkeletonOptions::use_current_as_root`] the archive root is the project
+/// root, so there is no `<project_name>/` directory.
+/// On a dry run nothing is written to `writer` and the report lists the planned
+/// entries.
+///
+/// # Errors
+///
+/// See [`SkeletonOptions::build`]. Failures of `writer` are returned as
+/// [`BuildError::IOError`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use python_skeleton::{SkeletonOptions, build_skeleton_zip};
+/// use std::fs::File;
+///
+/// let archive = File::create("My-Project.zip").unwrap();
+/// build_skeleton_zip(SkeletonOptions::new("My-Project", "my_package"), archive).unwrap();
+/// ```
+pub fn build_skeleton_zip(
+    options: SkeletonOptions,
+    writer: impl io::Write,
+) -> Result<BuildReport, BuildError> {
+    let dry_run = options.dry_run;
+    let fs = MemoryFileSystem::new();
+    let report = options.output_dir("").force(true).build_in(&fs)?;
+    if dry_run {
+        return Ok(report);
     }
-    let pkg_name = match validation::check_name(pkg_name, Case::SnakeCase) {
-        Ok(pkg_name) => pkg_name,
-        Err(error) => {
-            eprintln!("The name have an error: {error}");
-            return Err(BuildError::NameError);
-        }
+    let mut zip = ZipWriter::new_stream(writer);
+    let entry_options = SimpleFileOptions::default();
+    // Zip entries always use `/`, whatever the platform separator is.
+    let entry_name = |path: &PathBuf| {
+        let components: Vec<_> = path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect();
+        components.join("/")
     };
-    // Get safely current directory.
-    let mut dir = match current_dir() {
-        Ok(path) => path,
-        Err(error) => {
-            eprintln!("Can not get current directory: {error}");
-            return Err(BuildError::IOError);
+    let result = (|| -> zip::result::ZipResult<()> {
+        for dir in &report.created_dirs {
+            zip.add_directory(entry_name(dir), entry_options)?;
         }
-    };
-    // Make directories safely, delete all the created is error.
-    if let Err(error) =
-        dir_builder::make_dirs(&dir, &project_name, include_doc_dir, &pkg_name, verbose)
-    {
-        eprintln!("There was a prblem creating the directories: {error}");
-        if verbose {
-            println!("Falling back from directories creation");
+        for file in &report.created_files {
+            zip.start_file(entry_name(file), entry_options)?;
+            let content = fs.file(file).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} was not built", file.display()),
+                )
+            })?;
+            zip.write_all(&content)?;
         }
-        dir.push(&project_name);
-        let _ = remove_dir(dir);
-        return Err(BuildError::IOError);
+        zip.finish()?;
+        Ok(())
+    })();
+    if let Err(error) = result {
+        error!("There was a problem writing the archive: {error}");
+        return Err(BuildError::IOError(error.into()));
+    }
+    Ok(report)
+}
+
+/// Lists the paths [`build_skeleton`] would create, relative to the output
+/// directory, without writing anything.
+///
+/// This is a thin wrapper around [`SkeletonOptions::plan`].
+///
+/// # Errors
+///
+/// See [`SkeletonOptions::plan`].
+pub fn plan_skeleton(
+    project_name: String,
+    pkg_name: String,
+    include_doc_dir: bool,
+) -> Result<SkeletonPlan, BuildError> {
+    SkeletonOptions::new(project_name, pkg_name)
+        .docs(include_doc_dir)
+        .plan()
+}
+
+/// Checks the output directory `dir` before anything is written, and returns it
+/// with the `..` of its missing part resolved, see [`SkeletonOptions::build`].
+fn resolve_output_dir(fs: &impl FileSystem, dir: &Path) -> Result<PathBuf, BuildError> {
+    // The closest ancestor that exists, `""` standing for the current directory.
+    let existing = dir
+        .ancestors()
+        .find(|path| path.as_os_str().is_empty() || fs.exists(path))
+        .unwrap_or(Path::new(""));
+    // A file in the way of the output directory would only fail when writing.
+    if !existing.as_os_str().is_empty() && !fs.is_dir(existing) {
+        error!("The output path {} is not a directory", existing.display());
+        return Err(BuildError::OutputNotDirectory(existing.to_path_buf()));
     }
-    // Make the files safele, remove directories and files if an error.
-    if let Err(error) = files_builder::make_files(&project_name, &pkg_name, verbose) {
-        eprintln!("There was a problem creating the files. {error}");
-        if verbose {
-            println!("Falling back from files creation");
+    // Nothing below `existing` is on the filesystem yet, so no symlink can make
+    // `..` mean anything else than dropping the previous component.
+    let mut resolved = existing.to_path_buf();
+    let mut depth = 0;
+    for component in dir.strip_prefix(existing).unwrap_or(dir).components() {
+        match component {
+            Component::ParentDir if depth == 0 => {
+                error!(
+                    "The output path {} climbs out of {}",
+                    dir.display(),
+                    existing.display()
+                );
+                return Err(BuildError::OutputEscapes(dir.to_path_buf()));
+            }
+            Component::ParentDir => {
+                resolved.pop();
+                depth -= 1;
+            }
+            Component::CurDir => {}
+            component => {
+                resolved.push(component);
+                depth += 1;
+            }
         }
-        dir.push(&project_name);
-        let _ = remove_dir_all(dir);
-        return Err(BuildError::IOError);
     }
+    Ok(resolved)
+}
 
-    Ok(())
+/// Undoes a partial build, removing only the paths the builder created.
+///
+/// Files go first, then directories in reverse creation order so children are
+/// removed before their parents. [`FileSystem::remove_dir`] refuses non-empty
+/// directories, so foreign content is never deleted.
+fn rollback(fs: &impl FileSystem, created_files: &[PathBuf], created_dirs: &[PathBuf]) {
+    for file in created_files {
+        let _ = fs.remove_file(file);
+    }
+    for dir in created_dirs.iter().rev() {
+        let _ = fs.remove_dir(dir);
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use super::build_skeleton;
+    use super::{
+        BuildError, BuildPhase, Layout, License, SkeletonOptions, build_skeleton,
+        build_skeleton_zip, plan_skeleton,
+    };
+    use crate::file_system::{FileSystem, MemoryFileSystem};
+    use crate::files_builder::TemplateError;
+    use crate::validation::TrainCaseStyle;
+    use std::env::current_dir;
+    use std::fs::{create_dir, read_to_string, write};
+    use std::io::{self, Cursor, Read};
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use tempfile::tempdir;
+    use zip::ZipArchive;
 
     #[test]
     fn test_fail_name_build() {
-        assert!(build_skeleton("01".to_string(), "test".to_string(), true, false).is_err());
-        assert!(build_skeleton("test".to_string(), "test$".to_string(), true, false).is_err());
+        assert!(matches!(
+            build_skeleton(
+                "01".to_string(),
+                "test".to_string(),
+                false,
+                false,
+                false,
+                None,
+                &mut io::sink()
+            ),
+            Err(BuildError::NameError)
+        ));
+        assert!(matches!(
+            build_skeleton(
+                "test".to_string(),
+                "test$".to_string(),
+                false,
+                false,
+                false,
+                None,
+                &mut io::sink()
+            ),
+            Err(BuildError::NameError)
+        ));
+        assert!(matches!(
+            build_skeleton(
+                "".to_string(),
+                "".to_string(),
+                false,
+                false,
+                false,
+                None,
+                &mut io::sink()
+            ),
+            Err(BuildError::NameError)
+        ));
+    }
+
+    #[test]
+    fn test_error_is_boxable() {
+        let error: Box<dyn std::error::Error> = build_skeleton(
+            "01".to_string(),
+            "test".to_string(),
+            false,
+            false,
+            false,
+            None,
+            &mut io::sink(),
+        )
+        .unwrap_err()
+        .into();
+        assert_eq!(
+            error.to_string(),
+            "The project or package name is not valid"
+        );
+    }
+
+    #[test]
+    fn test_build_report() {
+        let dir = tempdir().unwrap();
+        let report = build_skeleton(
+            "Report-Build".to_string(),
+            "report_build".to_string(),
+            false,
+            false,
+            false,
+            Some(dir.path().to_path_buf()),
+            &mut io::sink(),
+        )
+        .unwrap();
+        let root = dir.path().join("Report-Build");
+
+        assert_eq!(report.created_dirs[0], root);
+        assert!(
+            report
+                .created_files
+                .iter()
+                .all(|file| file.starts_with(&root))
+        );
+        assert!(report.created_files.iter().all(|file| file.is_file()));
+    }
+
+    #[test]
+    fn test_rollback_keeps_existing_root() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("Existing-Build");
+        create_dir(&root).unwrap();
+        write(root.join("notes.txt"), "keep me").unwrap();
+        // A file where a directory is expected makes the build fail midway.
+        write(root.join("config"), "not a directory").unwrap();
+
+        let result = SkeletonOptions::new("Existing-Build", "existing_build")
+            .output_dir(dir.path())
+            .force(true)
+            .build();
+
+        let Err(BuildError::PathError { phase, path, .. }) = result else {
+            panic!("expected PathError, got {result:?}");
+        };
+        assert_eq!(phase, BuildPhase::Directories);
+        assert_eq!(path, root.join("config"));
+        assert!(root.join("notes.txt").is_file());
+        assert!(root.join("config").is_file());
+    }
+
+    #[test]
+    fn test_failing_file_is_reported() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("Blocked-Build");
+        // Permissions do not stop root, a directory in the way of a file does.
+        create_dir(&root).unwrap();
+        create_dir(root.join("README.md")).unwrap();
+
+        let error = SkeletonOptions::new("Blocked-Build", "blocked_build")
+            .output_dir(dir.path())
+            .force(true)
+            .build()
+            .unwrap_err();
+
+        let BuildError::PathError { phase, path, .. } = &error else {
+            panic!("expected PathError, got {error:?}");
+        };
+        assert_eq!(*phase, BuildPhase::Files);
+        assert_eq!(*path, root.join("README.md"));
+        assert!(error.to_string().starts_with(&format!(
+            "Can not create the file {}: ",
+            root.join("README.md").display()
+        )));
+        assert!(!root.join("pyproject.toml").exists());
+    }
+
+    #[test]
+    fn test_existing_root_is_refused() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("Existing-Root");
+        create_dir(&root).unwrap();
+
+        let result = SkeletonOptions::new("Existing-Root", "existing_root")
+            .output_dir(dir.path())
+            .build();
+
+        assert!(matches!(result, Err(BuildError::AlreadyExists(path)) if path == root));
+        assert_eq!(root.read_dir().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_dry_run_writes_nothing() {
+        let report = build_skeleton(
+            "Dry-Build".to_string(),
+            "dry_build".to_string(),
+            false,
+            true,
+            true,
+            None,
+            &mut io::sink(),
+        )
+        .unwrap();
+        let root = current_dir().unwrap().join("Dry-Build");
+
+        assert_eq!(report.created_dirs[0], root);
+        assert!(report.created_dirs.contains(&root.join("docs")));
+        assert!(report.created_files.contains(&root.join("pyproject.toml")));
+        assert!(!root.exists());
+    }
+
+    #[test]
+    fn test_options_builder() {
+        let dir = tempdir().unwrap();
+        let report = SkeletonOptions::new("builder-build", "Builder_Build")
+            .docs(true)
+            .output_dir(dir.path())
+            .build()
+            .unwrap();
+
+        assert_eq!(report.created_dirs[0], dir.path().join("Builder-Build"));
+        assert!(dir.path().join("Builder-Build/src/builder_build").is_dir());
+        assert!(dir.path().join("Builder-Build/docs").is_dir());
+    }
+
+    #[test]
+    fn test_nested_package_validation() {
+        let dir = tempdir().unwrap();
+        let result = SkeletonOptions::new("Nested-Build", "acme.Client")
+            .output_dir(dir.path())
+            .build();
+        assert!(result.is_ok());
+        assert!(
+            dir.path()
+                .join("Nested-Build/src/acme/client/env.py")
+                .is_file()
+        );
+
+        for invalid in ["acme..client", "acme.client-http", ".acme"] {
+            let result = SkeletonOptions::new("Nested-Build", invalid)
+                .output_dir(dir.path())
+                .dry_run(true)
+                .build();
+            assert!(matches!(result, Err(BuildError::NameError)));
+        }
+    }
+
+    #[test]
+    fn test_environment_validation() {
+        let plan = SkeletonOptions::new("Env-Build", "env_build")
+            .environments(["dev", "Staging"])
+            .plan()
+            .unwrap();
+        assert!(
+            plan.files
+                .contains(&Path::new("Env-Build/config/DEV.yaml").to_path_buf())
+        );
+        assert!(
+            plan.files
+                .contains(&Path::new("Env-Build/config/STAGING.yaml").to_path_buf())
+        );
+
+        for invalid in [&["PROD", "prod"][..], &["PROD-1"], &["../PROD"], &["_DEV"]] {
+            let result = SkeletonOptions::new("Env-Build", "env_build")
+                .environments(invalid.iter().copied())
+                .plan();
+            assert!(matches!(result, Err(BuildError::NameError)));
+        }
+    }
+
+    #[test]
+    fn test_failing_build_reports_progress() {
+        let fs = MemoryFileSystem::new();
+        fs.create_dir(Path::new("out/Progress-Build")).unwrap();
+        fs.write_file(Path::new("out/Progress-Build/notebooks"), b"")
+            .unwrap();
+        let mut progress = Vec::new();
+        let result = SkeletonOptions::new("Progress-Build", "progress_build")
+            .output_dir("out")
+            .force(true)
+            .build_in_with_progress(&fs, &mut progress);
+
+        assert!(matches!(result, Err(BuildError::PathError { .. })));
+        assert_eq!(
+            String::from_utf8(progress).unwrap(),
+            "Created directory out/Progress-Build/config\n\
+             Created directory out/Progress-Build/files\n"
+        );
+        assert!(!fs.exists(Path::new("out/Progress-Build/config")));
+    }
+
+    #[test]
+    fn test_build_with_progress() {
+        let dir = tempdir().unwrap();
+        let mut progress = Vec::new();
+        SkeletonOptions::new("Progress-Build", "progress_build")
+            .output_dir(dir.path())
+            .build_with_progress(&mut progress)
+            .unwrap();
+        let progress = String::from_utf8(progress).unwrap();
+
+        let root = dir.path().join("Progress-Build");
+        assert!(progress.starts_with(&format!("Created directory {}\n", root.display())));
+        assert!(progress.contains(&format!(
+            "Created directory {}\n",
+            root.join("config").display()
+        )));
+        assert!(progress.contains(&format!(
+            "Created file {}\n",
+            root.join("pyproject.toml").display()
+        )));
+    }
+
+    #[test]
+    fn test_build_into_output_dir() {
+        let dir = tempdir().unwrap();
+        let result = build_skeleton(
+            "Output-Build".to_string(),
+            "output_build".to_string(),
+            false,
+            true,
+            false,
+            Some(dir.path().to_path_buf()),
+            &mut io::sink(),
+        );
+
+        assert!(result.is_ok());
+        assert!(dir.path().join("Output-Build/docs").is_dir());
+        assert!(
+            dir.path()
+                .join("Output-Build/src/output_build/main.py")
+                .is_file()
+        );
+    }
+
+    #[test]
+    fn test_build_in_current_dir() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Here-Build", "here_build")
+            .output_dir(dir.path())
+            .use_current_as_root(true);
+        let report = options.clone().build().unwrap();
+        assert_eq!(report.created_dirs[0], dir.path().join("config"));
+        assert!(dir.path().join("pyproject.toml").is_file());
+        assert!(!dir.path().join("Here-Build").exists());
+
+        let result = options.clone().build();
+        assert!(matches!(result, Err(BuildError::NotEmpty(path)) if path == dir.path()));
+        assert!(options.force(true).build().is_ok());
+
+        let missing = SkeletonOptions::new("Here-Build", "here_build")
+            .output_dir(dir.path().join("missing"))
+            .use_current_as_root(true)
+            .build();
+        assert!(
+            matches!(missing, Err(BuildError::IOError(error)) if error.kind() == std::io::ErrorKind::NotFound)
+        );
+    }
+
+    #[test]
+    fn test_update_only_adds_missing_files() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Update-Build", "update_build").output_dir(dir.path());
+        let first = options.clone().build().unwrap();
+        let root = dir.path().join("Update-Build");
+        write(root.join("README.md"), "# Edited").unwrap();
+
+        let report = options.changelog(true).update(true).build().unwrap();
+        assert!(report.created_dirs.is_empty());
+        assert_eq!(report.created_files, vec![root.join("CHANGELOG.md")]);
+        assert_eq!(report.skipped_files.len(), first.created_files.len());
+        assert_eq!(read_to_string(root.join("README.md")).unwrap(), "# Edited");
+    }
+
+    #[test]
+    fn test_extra_files() {
+        let dir = tempdir().unwrap();
+        let report = SkeletonOptions::new("Extra-Build", "extra_build")
+            .output_dir(dir.path())
+            .python_version("3.12")
+            .extra_files([
+                (".tool-versions", "python {{python_version}}\n"),
+                (".github/renovate.json", "{}\n"),
+                ("README.md", "# Ours\n"),
+                ("NOTES.md", "Hard  \nbreak\n\n"),
+            ])
+            .build()
+            .unwrap();
+        let root = dir.path().join("Extra-Build");
+        assert!(
+            report
+                .created_files
+                .contains(&root.join(".github/renovate.json"))
+        );
+        assert_eq!(
+            read_to_string(root.join(".tool-versions")).unwrap(),
+            "python 3.12\n"
+        );
+        assert_eq!(read_to_string(root.join("README.md")).unwrap(), "# Ours\n");
+        // Extra files are written as given, trailing whitespace included.
+        assert_eq!(
+            read_to_string(root.join("NOTES.md")).unwrap(),
+            "Hard  \nbreak\n\n"
+        );
+
+        let escaping = SkeletonOptions::new("Extra-Build", "extra_build")
+            .extra_files([("../outside.txt", "")])
+            .plan();
+        assert!(
+            matches!(escaping, Err(BuildError::InvalidExtraFile(path)) if path == Path::new("../outside.txt"))
+        );
+    }
+
+    #[test]
+    fn test_output_dir_is_a_file() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("projects");
+        write(&file, "").unwrap();
+
+        for output_dir in [file.clone(), file.join("nested")] {
+            let result = SkeletonOptions::new("File-Build", "file_build")
+                .output_dir(output_dir)
+                .build();
+            assert!(matches!(result, Err(BuildError::OutputNotDirectory(path)) if path == file));
+        }
+        assert_eq!(dir.path().read_dir().unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_output_dir_parent_components() {
+        let dir = tempdir().unwrap();
+        let report = SkeletonOptions::new("Dots-Build", "dots_build")
+            .output_dir(dir.path().join("new/../projects/./python"))
+            .build()
+            .unwrap();
+        let root = dir.path().join("projects/python/Dots-Build");
+        assert_eq!(report.created_dirs[0], root);
+        assert!(root.join("pyproject.toml").is_file());
+        assert!(!dir.path().join("new").exists());
+
+        let output_dir = dir.path().join("projects/missing/../../../escaped");
+        let result = SkeletonOptions::new("Dots-Build", "dots_build")
+            .output_dir(&output_dir)
+            .build();
+        assert!(matches!(result, Err(BuildError::OutputEscapes(path)) if path == output_dir));
+        assert!(!dir.path().join("projects/missing").exists());
+    }
+
+    #[test]
+    fn test_derived_package_name() {
+        let dir = tempdir().unwrap();
+        let report = build_skeleton(
+            "my-cool-lib".to_string(),
+            String::new(),
+            false,
+            false,
+            false,
+            Some(dir.path().to_path_buf()),
+            &mut io::sink(),
+        )
+        .unwrap();
+
+        let package = dir.path().join("My-Cool-Lib/src/my_cool_lib");
+        assert!(package.is_dir());
+        assert!(report.created_files.contains(&package.join("main.py")));
+    }
+
+    #[test]
+    fn test_acronyms_in_project_name() {
+        let plan = SkeletonOptions::new("my-API-client", "").plan().unwrap();
+        assert_eq!(plan.dirs[0], Path::new("My-Api-Client"));
+
+        let plan = SkeletonOptions::new("my-API-client", "")
+            .train_case_style(TrainCaseStyle::PreserveAcronyms)
+            .plan()
+            .unwrap();
+        assert_eq!(plan.dirs[0], Path::new("My-API-Client"));
+        assert!(
+            plan.dirs
+                .contains(&PathBuf::from("My-API-Client/src/my_api_client"))
+        );
+    }
+
+    #[test]
+    fn test_long_paths_are_refused_early() {
+        let dir = tempdir().unwrap();
+        let result = SkeletonOptions::new("A".repeat(300), "long_package")
+            .output_dir(dir.path())
+            .build();
+        assert!(matches!(result, Err(BuildError::NameError)));
+
+        // Valid names, but too deep for the limit.
+        let package = ["deeply_nested_segment"; 4].join(".");
+        let result = SkeletonOptions::new("Long-Project", &package)
+            .output_dir(dir.path())
+            .max_path_len(120)
+            .build();
+        let Err(BuildError::PathTooLong { path, limit }) = result else {
+            panic!("expected PathTooLong, got {result:?}");
+        };
+        assert_eq!(limit, 120);
+        assert!(path.to_string_lossy().chars().count() > 120);
+        assert_eq!(dir.path().read_dir().unwrap().count(), 0);
+
+        // The default limit leaves room for it.
+        assert!(
+            SkeletonOptions::new("Long-Project", &package)
+                .output_dir(dir.path())
+                .dry_run(true)
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_flat_layout_build() {
+        let dir = tempdir().unwrap();
+        let report = SkeletonOptions::new("Flat-Build", "flat_build")
+            .layout(Layout::FlatLayout)
+            .output_dir(dir.path())
+            .build()
+            .unwrap();
+        let root = dir.path().join("Flat-Build");
+
+        assert!(root.join("flat_build/__init__.py").is_file());
+        assert!(!root.join("src").exists());
+        assert!(!report.created_dirs.contains(&root.join("src")));
+    }
+
+    #[test]
+    fn test_plan_matches_dry_run() {
+        let dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Plan-Project", "acme.plan").output_dir(dir.path());
+        let plan = options.plan().unwrap();
+        let report = options.dry_run(true).build().unwrap();
+        let relative = |paths: &[std::path::PathBuf]| -> Vec<_> {
+            paths
+                .iter()
+                .map(|path| path.strip_prefix(dir.path()).unwrap().to_path_buf())
+                .collect()
+        };
+        assert_eq!(plan.dirs, relative(&report.created_dirs));
+        assert_eq!(plan.files, relative(&report.created_files));
+        assert_eq!(dir.path().read_dir().unwrap().count(), 0);
+        assert!(matches!(
+            plan_skeleton("Plan-Project".to_string(), "not valid".to_string(), false),
+            Err(BuildError::NameError)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_plan_serializes_to_json() {
+        let plan = plan_skeleton("Json-Project".to_string(), "json_pkg".to_string(), true).unwrap();
+        let json: serde_json::Value = serde_json::to_value(&plan).unwrap();
+        let dirs = json["dirs"].as_array().unwrap();
+        assert_eq!(dirs[0], "Json-Project");
+        assert!(dirs.contains(&"Json-Project/docs".into()));
+        assert!(dirs.contains(&"Json-Project/src/json_pkg".into()));
+        let files = json["files"].as_array().unwrap();
+        assert!(files.contains(&"Json-Project/src/json_pkg/__init__.py".into()));
+        assert!(files.contains(&"Json-Project/pyproject.toml".into()));
+    }
+
+    #[test]
+    fn test_build_in_memory() {
+        let fs = MemoryFileSystem::new();
+        let report = SkeletonOptions::new("Memory-Project", "memory_pkg")
+            .output_dir("")
+            .license(License::Mit)
+            .build_in(&fs)
+            .unwrap();
+        assert_eq!(report.created_dirs[0], Path::new("Memory-Project"));
+        assert!(fs.is_dir(Path::new("Memory-Project/src/memory_pkg")));
+
+        let files = fs.into_files();
+        assert_eq!(files.len(), report.created_files.len());
+        let init = &files[Path::new("Memory-Project/src/memory_pkg/__init__.py")];
+        assert!(String::from_utf8_lossy(init).contains("__version__ = \"0.1.0\""));
+        let license = &files[Path::new("Memory-Project/LICENSE")];
+        assert!(license.starts_with(b"MIT License"));
+        assert!(!Path::new("Memory-Project").exists());
+    }
+
+    #[test]
+    fn test_build_zip() {
+        let mut archive = Vec::new();
+        let report =
+            build_skeleton_zip(SkeletonOptions::new("Zip-Project", "zip_pkg"), &mut archive)
+                .unwrap();
+        let mut zip = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(
+            zip.len(),
+            report.created_dirs.len() + report.created_files.len()
+        );
+        assert!(zip.by_name("Zip-Project/src/zip_pkg/").unwrap().is_dir());
+        assert!(zip.by_name("Zip-Project/pyproject.toml").is_ok());
+        let mut readme = String::new();
+        zip.by_name("Zip-Project/README.md")
+            .unwrap()
+            .read_to_string(&mut readme)
+            .unwrap();
+        assert!(readme.starts_with("# Zip-Project"));
+    }
+
+    #[test]
+    fn test_build_zip_current_as_root() {
+        let mut archive = Vec::new();
+        build_skeleton_zip(
+            SkeletonOptions::new("Zip-Project", "zip_pkg").use_current_as_root(true),
+            &mut archive,
+        )
+        .unwrap();
+        let mut zip = ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert!(zip.by_name("README.md").is_ok());
+        assert!(zip.by_name("src/zip_pkg/").unwrap().is_dir());
+        assert!(zip.by_name("Zip-Project/README.md").is_err());
+    }
+
+    #[test]
+    fn test_build_zip_dry_run() {
+        let mut archive = Vec::new();
+        let report = build_skeleton_zip(
+            SkeletonOptions::new("Zip-Project", "zip_pkg").dry_run(true),
+            &mut archive,
+        )
+        .unwrap();
+        assert!(archive.is_empty());
+        assert!(
+            report
+                .created_files
+                .contains(&PathBuf::from("Zip-Project/README.md"))
+        );
+    }
+
+    #[test]
+    fn test_template_dir_build() {
+        let dir = tempdir().unwrap();
+        let template_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/template");
+        let options = SkeletonOptions::new("Custom-Project", "custom_pkg")
+            .author("Jane Doe")
+            .template_dir(template_dir)
+            .output_dir(dir.path());
+        let plan = options.plan().unwrap();
+        let report = options.build().unwrap();
+        let root = dir.path().join("Custom-Project");
+
+        let readme = read_to_string(root.join("README.md")).unwrap();
+        assert_eq!(
+            readme,
+            "# Custom-Project\nBuilt from a custom template by Jane Doe.\n"
+        );
+        let notes = read_to_string(root.join("custom_pkg_notes.md")).unwrap();
+        assert_eq!(notes, "# Notes for Custom-Project\n");
+        let script = read_to_string(root.join("scripts/run_custom_pkg.sh")).unwrap();
+        assert!(script.contains("python -m custom_pkg"));
+        assert!(report.created_dirs.contains(&root.join("scripts")));
+        // The README is replaced, not written twice.
+        assert_eq!(report.created_files.len(), plan.files.len());
+        assert_eq!(
+            plan.files
+                .iter()
+                .filter(|file| file.ends_with("README.md"))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_template_dir_errors() {
+        let template_dir = tempdir().unwrap();
+        let options = SkeletonOptions::new("Custom-Project", "custom_pkg")
+            .template_dir(template_dir.path())
+            .output_dir("");
+        write(template_dir.path().join("notes.md"), "{{unknown}}").unwrap();
+        assert!(matches!(
+            options.plan(),
+            Err(BuildError::TemplateError(TemplateError::MissingVariable(name))) if name == "unknown"
+        ));
+
+        write(template_dir.path().join("notes.md"), [0xff, 0xfe]).unwrap();
+        assert!(matches!(options.plan(), Err(BuildError::IOError(_))));
+    }
+
+    #[test]
+    fn test_python_version_validation() {
+        let dir = tempdir().unwrap();
+        for version in ["3", "3.", "3.12.1", "py312", "3.x", " 3.12"] {
+            let result = SkeletonOptions::new("Version-Project", "version_pkg")
+                .python_version(version)
+                .output_dir(dir.path())
+                .build();
+            assert!(matches!(result, Err(BuildError::PythonVersionError(v)) if v == version));
+        }
+        assert_eq!(dir.path().read_dir().unwrap().count(), 0);
+        let plan = SkeletonOptions::new("Version-Project", "version_pkg")
+            .python_version("3.12")
+            .plan();
+        assert!(plan.is_ok());
+    }
+
+    #[test]
+    fn test_init_git() {
+        let git = Command::new("git").arg("--version").output();
+        if !git.is_ok_and(|output| output.status.success()) {
+            return;
+        }
+        let dir = tempdir().unwrap();
+        let report = SkeletonOptions::new("Git-Project", "git_pkg")
+            .init_git(true)
+            .git_message("Scaffold the project")
+            .output_dir(dir.path())
+            .build()
+            .unwrap();
+        let root = dir.path().join("Git-Project");
+        assert!(root.join(".git").is_dir());
+        // Without a committer identity the commit fails, but the build does not.
+        if report.warnings.is_empty() {
+            let log = Command::new("git")
+                .args(["log", "--format=%s"])
+                .current_dir(&root)
+                .output()
+                .unwrap();
+            assert_eq!(
+                String::from_utf8_lossy(&log.stdout),
+                "Scaffold the project\n"
+            );
+        } else {
+            assert!(report.warnings[0].contains("git commit"));
+        }
     }
 }
@@ -1,50 +1,1109 @@
-use clap::{Arg, ArgAction, Command, command};
-use python_skeleton::build_skeleton;
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches, Command, command, value_parser};
+use clap_complete::{Shell, generate};
+use log::LevelFilter;
+use python_skeleton::validation::{
+    Case, TrainCaseStyle, ValidationOptions, check_name, convert_case, validate_name_collect,
+};
+use python_skeleton::{
+    BuildBackend, BuildError, BuildReport, Database, DocEngine, GitignorePreset, Layout, License,
+    PackagingStyle, SkeletonOptions, TestRunner,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::env::current_dir;
+use std::fs::read_to_string;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
 fn cmd() -> Command {
     command!()
         .next_line_help(true)
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Print every validation step, directory and file while building."),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("verbose")
+                .help("Print nothing but errors, the exit code tells the result."),
+        )
+        .subcommand(new_cmd())
+        .subcommand(
+            Command::new("validate")
+                .about("Check a name against a naming convention and print it normalized.")
+                .arg(
+                    Arg::new("name")
+                        .required(true)
+                        .value_name("NAME")
+                        .help("The name to check."),
+                )
+                .arg(
+                    Arg::new("case")
+                        .long("case")
+                        .value_parser([
+                            "snake",
+                            "screaming-snake",
+                            "kebab",
+                            "train",
+                            "pascal",
+                            "camel",
+                        ])
+                        .default_value("snake")
+                        .help("The naming convention the name must follow."),
+                ),
+        )
+        .subcommand(
+            Command::new("list-templates")
+                .about("List every directory and file the builder can generate."),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Print the completion script of a shell.")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_name("SHELL")
+                        .value_parser(value_parser!(Shell))
+                        .help("The shell to complete for."),
+                ),
+        )
+}
+
+fn new_cmd() -> Command {
+    Command::new("new")
+        .about("Create a new python project.")
         .arg(
             Arg::new("project")
-                .required(true)
+                .required_unless_present_any(["config", "interactive"])
                 .value_name("PROJECT_NAME")
                 .help("Name of the root directory of the project. It mus be Train-Case."),
         )
         .arg(
             Arg::new("package")
-                .required(true)
                 .value_name("PKG_NAME")
-                .help("Name of the package. It must be snake_case."),
-        )
-        .arg(
-            Arg::new("verbose")
-                .short('v')
-                .long("verbose")
-                .action(ArgAction::SetTrue),
+                .help("Name of the package. It must be snake_case. Defaults to the snake_case project name."),
         )
         .arg(
             Arg::new("doc")
                 .long("doc")
                 .action(ArgAction::SetTrue)
-                .help("If present, create a directory `docs` for documentation of the package."),
+                .help("If present, create a directory `docs` for documentation of the package, with a starter config."),
+        )
+        .arg(
+            Arg::new("doc_engine")
+                .long("doc-engine")
+                .value_parser(["mkdocs", "sphinx"])
+                .default_value("mkdocs")
+                .help("Documentation tool configured with --doc."),
+        )
+        .arg(
+            Arg::new("ci")
+                .long("ci")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a GitHub Actions workflow running ruff and pytest."),
+        )
+        .arg(
+            Arg::new("docker")
+                .long("docker")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a multi-stage `Dockerfile` and a `.dockerignore`."),
+        )
+        .arg(
+            Arg::new("precommit")
+                .long("pre-commit")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `.pre-commit-config.yaml` with ruff hooks."),
+        )
+        .arg(
+            Arg::new("editorconfig")
+                .long("editorconfig")
+                .action(ArgAction::SetTrue)
+                .help("If present, add an `.editorconfig` for Python and YAML files."),
+        )
+        .arg(
+            Arg::new("community")
+                .long("community")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `CONTRIBUTING.md` and a `CODE_OF_CONDUCT.md`."),
+        )
+        .arg(
+            Arg::new("changelog")
+                .long("changelog")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `CHANGELOG.md` in the Keep a Changelog format."),
+        )
+        .arg(
+            Arg::new("security")
+                .long("security")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `SECURITY.md` telling how to report vulnerabilities."),
+        )
+        .arg(
+            Arg::new("security_email")
+                .long("security-email")
+                .value_name("EMAIL")
+                .help("Contact of the `SECURITY.md`. Defaults to security@example.com."),
+        )
+        .arg(
+            Arg::new("makefile")
+                .long("makefile")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `Makefile` with install, test, lint, format and run tasks."),
+        )
+        .arg(
+            Arg::new("conftest")
+                .long("conftest")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `conftest.py` with an example fixture to the tests."),
+        )
+        .arg(
+            Arg::new("requirements")
+                .long("requirements")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `requirements.txt` with the dependencies of `pyproject.toml`."),
+        )
+        .arg(
+            Arg::new("git")
+                .long("git")
+                .action(ArgAction::SetTrue)
+                .help("If present, run `git init` and commit every file once built."),
+        )
+        .arg(
+            Arg::new("git_message")
+                .long("git-message")
+                .value_name("MESSAGE")
+                .help("Message of the initial commit. Defaults to \"Initial commit\"."),
+        )
+        .arg(
+            Arg::new("python")
+                .long("python")
+                .value_name("X.Y")
+                .help("Python version the project targets, e.g. 3.12. Defaults to 3.14."),
+        )
+        .arg(
+            Arg::new("python_version_file")
+                .long("python-version-file")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `.python-version` file pinning the interpreter."),
+        )
+        .arg(
+            Arg::new("version_file")
+                .long("version-file")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `VERSION` file with the version of the project."),
+        )
+        .arg(
+            Arg::new("database")
+                .long("database")
+                .value_parser(["oracle", "postgres", "mysql", "sqlite", "none"])
+                .default_value("none")
+                .help("Database reached by the generated `db.py`. With `none` it is not written."),
+        )
+        .arg(
+            Arg::new("test_runner")
+                .long("test-runner")
+                .value_parser(["tox", "nox", "none"])
+                .default_value("none")
+                .help("Write a `tox.ini` or `noxfile.py` testing every Python version from --python on."),
+        )
+        .arg(
+            Arg::new("gitignore")
+                .long("gitignore")
+                .value_parser(["python", "python-data", "python-web"])
+                .default_value("python")
+                .help("Patterns of the `.gitignore`, adding those of data or web projects to the Python ones."),
+        )
+        .arg(
+            Arg::new("no_env")
+                .long("no-env")
+                .action(ArgAction::SetTrue)
+                .help("If present, leave out the `env.py` module and its dependencies."),
+        )
+        .arg(
+            Arg::new("environment")
+                .long("environment")
+                .value_name("ENV")
+                .action(ArgAction::Append)
+                .help("Environment getting a `config/<ENV>.yaml`, repeat it for several. Defaults to DEV."),
+        )
+        .arg(
+            Arg::new("typing")
+                .long("typing")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `py.typed` marker and a mypy configuration."),
+        )
+        .arg(
+            Arg::new("logging")
+                .long("logging")
+                .action(ArgAction::SetTrue)
+                .help("If present, add a `logging_config.py` setting up structlog, imported by the package."),
+        )
+        .arg(
+            Arg::new("keep_acronyms")
+                .long("keep-acronyms")
+                .action(ArgAction::SetTrue)
+                .help("If present, keep the uppercase runs of the project name, e.g. `My-API-Client` instead of `My-Api-Client`."),
+        )
+        .arg(
+            Arg::new("layout")
+                .long("layout")
+                .value_parser(["src", "flat"])
+                .default_value("src")
+                .help("Place the package under `src/` or directly in the project root."),
+        )
+        .arg(
+            Arg::new("namespace_package")
+                .long("namespace-package")
+                .action(ArgAction::SetTrue)
+                .help("If present, leave the `__init__.py` out of the parents of a dotted package, as PEP 420 namespaces."),
+        )
+        .arg(
+            Arg::new("description")
+                .long("description")
+                .value_name("TEXT")
+                .help("One-line description written under the README title."),
+        )
+        .arg(
+            Arg::new("license")
+                .long("license")
+                .value_parser(["mit", "apache-2.0", "bsd-3-clause", "gpl-3.0", "none"])
+                .default_value("none")
+                .help("License of the project, written to `LICENSE` and `pyproject.toml`."),
+        )
+        .arg(
+            Arg::new("backend")
+                .long("backend")
+                .value_parser(["setuptools", "hatchling", "poetry", "pdm"])
+                .default_value("setuptools")
+                .help("Build backend declared in `pyproject.toml`."),
+        )
+        .arg(
+            Arg::new("packaging")
+                .long("packaging")
+                .value_parser(["modern", "legacy"])
+                .default_value("modern")
+                .help("`legacy` also writes `setup.cfg` and `setup.py`, built with setuptools."),
+        )
+        .arg(
+            Arg::new("author")
+                .long("author")
+                .value_name("NAME")
+                .default_value("Your Name")
+                .help("Author written into `pyproject.toml` and the README."),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .value_name("DIR")
+                .value_parser(value_parser!(PathBuf))
+                .help("Directory where the project is created, made if missing. Defaults to the current one."),
+        )
+        .arg(
+            Arg::new("template_dir")
+                .long("template-dir")
+                .value_name("DIR")
+                .value_parser(value_parser!(PathBuf))
+                .help("Directory of your own templates, copied into the project with its {{placeholders}} filled."),
+        )
+        .arg(
+            Arg::new("template_overrides_dir")
+                .long("template-overrides-dir")
+                .value_name("DIR")
+                .value_parser(value_parser!(PathBuf))
+                .help("Directory of templates replacing the built-in ones with the same path, e.g. `README.md`."),
+        )
+        .arg(
+            Arg::new("interactive")
+                .short('i')
+                .long("interactive")
+                .action(ArgAction::SetTrue)
+                .help("Ask for the names, docs, database and license instead."),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("FILE")
+                .value_parser(value_parser!(PathBuf))
+                .help("TOML file with default values for these options, e.g. `skeleton.toml`."),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .action(ArgAction::SetTrue)
+                .help("Build even if the project directory exists, overwriting its generated files."),
+        )
+        .arg(
+            Arg::new("update")
+                .long("update")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("force")
+                .help("Add only the missing directories and files to an existing project, keeping the others."),
+        )
+        .arg(
+            Arg::new("here")
+                .long("here")
+                .action(ArgAction::SetTrue)
+                .help("Build into the output directory itself, which must be empty, instead of a new project folder."),
+        )
+        .arg(
+            Arg::new("max_path_len")
+                .long("max-path-len")
+                .value_name("N")
+                .value_parser(value_parser!(usize))
+                .help("Longest path allowed, checked before writing anything. Defaults to 255."),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["text", "json"])
+                .default_value("text")
+                .help("Print the created directories and files, or the error, as a JSON object."),
+        )
+        .arg(
+            Arg::new("dry_run")
+                .long("dry-run")
+                .action(ArgAction::SetTrue)
+                .help("Print the planned directories and files without writing anything."),
         )
 }
 
-fn main() {
-    let matches = cmd().get_matches();
-    let result = build_skeleton(
-        matches.get_one::<String>("project").unwrap().to_string(),
-        matches.get_one::<String>("package").unwrap().to_string(),
-        matches.get_flag("verbose"),
-        matches.get_flag("doc"),
-    );
+/// Defaults for the `new` subcommand, read from a TOML file given with
+/// `--config`. Every key is optional, and flags given on the command line win
+/// over the file:
+///
+/// ```toml
+/// project = "My-Project"        # PROJECT_NAME
+/// package = "my_package"        # PKG_NAME
+/// output = "./projects"         # --output
+/// here = true                   # --here
+/// max-path-len = 200            # --max-path-len
+/// author = "Jane Doe"           # --author
+/// description = "Does things."  # --description
+/// license = "mit"               # --license
+/// backend = "poetry"            # --backend
+/// packaging = "legacy"          # --packaging
+/// database = "postgres"         # --database
+/// test-runner = "tox"           # --test-runner
+/// gitignore = "python-data"     # --gitignore
+/// layout = "flat"               # --layout
+/// namespace-package = true      # --namespace-package
+/// keep-acronyms = true          # --keep-acronyms
+/// docs = true                   # --doc
+/// doc-engine = "sphinx"         # --doc-engine
+/// ci = true                     # --ci
+/// docker = true                 # --docker
+/// pre-commit = true             # --pre-commit
+/// editorconfig = true           # --editorconfig
+/// community = true              # --community
+/// changelog = true              # --changelog
+/// security = true               # --security
+/// security-email = "s@acme.org" # --security-email
+/// makefile = true               # --makefile
+/// conftest = true               # --conftest
+/// git = true                    # --git
+/// git-message = "Scaffold"      # --git-message
+/// python = "3.12"               # --python
+/// python-version-file = true    # --python-version-file
+/// version-file = true           # --version-file
+/// requirements = true           # --requirements
+/// typing = true                 # --typing
+/// logging = true                # --logging
+/// env = false                   # --no-env
+/// environments = ["PROD"]       # --environment
+/// ```
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+struct SkeletonConfig {
+    project: Option<String>,
+    package: Option<String>,
+    output: Option<PathBuf>,
+    here: Option<bool>,
+    max_path_len: Option<usize>,
+    author: Option<String>,
+    description: Option<String>,
+    license: Option<String>,
+    backend: Option<String>,
+    packaging: Option<String>,
+    database: Option<String>,
+    test_runner: Option<String>,
+    gitignore: Option<String>,
+    layout: Option<String>,
+    namespace_package: Option<bool>,
+    keep_acronyms: Option<bool>,
+    docs: Option<bool>,
+    doc_engine: Option<String>,
+    ci: Option<bool>,
+    docker: Option<bool>,
+    pre_commit: Option<bool>,
+    editorconfig: Option<bool>,
+    community: Option<bool>,
+    changelog: Option<bool>,
+    security: Option<bool>,
+    security_email: Option<String>,
+    makefile: Option<bool>,
+    conftest: Option<bool>,
+    git: Option<bool>,
+    git_message: Option<String>,
+    python: Option<String>,
+    python_version_file: Option<bool>,
+    version_file: Option<bool>,
+    requirements: Option<bool>,
+    typing: Option<bool>,
+    logging: Option<bool>,
+    env: Option<bool>,
+    environments: Option<Vec<String>>,
+}
+
+impl SkeletonConfig {
+    /// Reads the file given with `--config`, or the empty config without one.
+    fn load(matches: &ArgMatches) -> Result<Self, String> {
+        let Some(path) = matches.get_one::<PathBuf>("config") else {
+            return Ok(SkeletonConfig::default());
+        };
+        let content = read_to_string(path)
+            .map_err(|error| format!("Can not read {}: {error}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(|error| format!("Invalid config {}: {error}", path.display()))
+    }
+}
+
+/// The value of the argument `id`: the command line first, then the `config`
+/// file, then the default of the argument.
+fn pick<'a>(matches: &'a ArgMatches, id: &str, config: Option<&'a String>) -> Option<&'a str> {
+    let cli = matches.get_one::<String>(id).map(String::as_str);
+    if matches.value_source(id) == Some(ValueSource::CommandLine) {
+        cli
+    } else {
+        config.map(String::as_str).or(cli)
+    }
+}
+
+/// The output directory, from the command line or the `config` file.
+fn output_dir(matches: &ArgMatches, config: &SkeletonConfig) -> Option<PathBuf> {
+    matches
+        .get_one::<PathBuf>("output")
+        .or(config.output.as_ref())
+        .cloned()
+}
+
+/// Maps the arguments of the `new` subcommand, merged with `config`, onto the
+/// library options. Values coming from the file are checked here, since clap
+/// only knows about the command line.
+fn new_options(matches: &ArgMatches, config: &SkeletonConfig) -> Result<SkeletonOptions, String> {
+    let Some(project) = pick(matches, "project", config.project.as_ref()) else {
+        return Err("The project name is required".to_string());
+    };
+    // An empty package name is derived from the project one when building.
+    let package = pick(matches, "package", config.package.as_ref()).unwrap_or_default();
+    let flag = |id: &str, config: Option<bool>| matches.get_flag(id) || config.unwrap_or(false);
+    let unknown = |what: &str, value: &str| format!("Unknown {what} `{value}`");
+    let database = match pick(matches, "database", config.database.as_ref()).unwrap() {
+        "oracle" => Database::Oracle,
+        "postgres" => Database::Postgres,
+        "mysql" => Database::MySql,
+        "sqlite" => Database::Sqlite,
+        "none" => Database::None,
+        other => return Err(unknown("database", other)),
+    };
+    let test_runner = match pick(matches, "test_runner", config.test_runner.as_ref()).unwrap() {
+        "tox" => TestRunner::Tox,
+        "nox" => TestRunner::Nox,
+        "none" => TestRunner::None,
+        other => return Err(unknown("test runner", other)),
+    };
+    let gitignore_preset = match pick(matches, "gitignore", config.gitignore.as_ref()).unwrap() {
+        "python" => GitignorePreset::Python,
+        "python-data" => GitignorePreset::PythonData,
+        "python-web" => GitignorePreset::PythonWeb,
+        other => return Err(unknown("gitignore preset", other)),
+    };
+    let layout = match pick(matches, "layout", config.layout.as_ref()).unwrap() {
+        "src" => Layout::SrcLayout,
+        "flat" => Layout::FlatLayout,
+        other => return Err(unknown("layout", other)),
+    };
+    let backend = match pick(matches, "backend", config.backend.as_ref()).unwrap() {
+        "setuptools" => BuildBackend::Setuptools,
+        "hatchling" => BuildBackend::Hatchling,
+        "poetry" => BuildBackend::Poetry,
+        "pdm" => BuildBackend::Pdm,
+        other => return Err(unknown("backend", other)),
+    };
+    let packaging = match pick(matches, "packaging", config.packaging.as_ref()).unwrap() {
+        "modern" => PackagingStyle::Modern,
+        "legacy" => PackagingStyle::Legacy,
+        other => return Err(unknown("packaging style", other)),
+    };
+    let doc_engine = match pick(matches, "doc_engine", config.doc_engine.as_ref()).unwrap() {
+        "mkdocs" => DocEngine::MkDocs,
+        "sphinx" => DocEngine::Sphinx,
+        other => return Err(unknown("doc engine", other)),
+    };
+    let license = match pick(matches, "license", config.license.as_ref()).unwrap() {
+        "mit" => License::Mit,
+        "apache-2.0" => License::Apache2,
+        "bsd-3-clause" => License::Bsd3Clause,
+        "gpl-3.0" => License::Gpl3,
+        "none" => License::None,
+        other => return Err(unknown("license", other)),
+    };
+    let mut options = SkeletonOptions::new(project, package)
+        .docs(flag("doc", config.docs))
+        .doc_engine(doc_engine)
+        .dry_run(matches.get_flag("dry_run"))
+        .force(matches.get_flag("force"))
+        .update(matches.get_flag("update"))
+        .use_current_as_root(flag("here", config.here))
+        .ci(flag("ci", config.ci))
+        .docker(flag("docker", config.docker))
+        .precommit(flag("precommit", config.pre_commit))
+        .editorconfig(flag("editorconfig", config.editorconfig))
+        .community_files(flag("community", config.community))
+        .changelog(flag("changelog", config.changelog))
+        .security(flag("security", config.security))
+        .makefile(flag("makefile", config.makefile))
+        .conftest(flag("conftest", config.conftest))
+        .python_version_file(flag("python_version_file", config.python_version_file))
+        .version_file(flag("version_file", config.version_file))
+        .requirements_txt(flag("requirements", config.requirements))
+        .typing(flag("typing", config.typing))
+        .logging(flag("logging", config.logging))
+        .database(database)
+        .test_runner(test_runner)
+        .gitignore_preset(gitignore_preset)
+        .env(!matches.get_flag("no_env") && config.env.unwrap_or(true))
+        .author(pick(matches, "author", config.author.as_ref()).unwrap())
+        .layout(layout)
+        .namespace_package(flag("namespace_package", config.namespace_package))
+        .train_case_style(if flag("keep_acronyms", config.keep_acronyms) {
+            TrainCaseStyle::PreserveAcronyms
+        } else {
+            TrainCaseStyle::Capitalize
+        })
+        .backend(backend)
+        .packaging(packaging)
+        .license(license);
+    if let Some(description) = pick(matches, "description", config.description.as_ref()) {
+        options = options.description(description);
+    }
+    if let Some(environments) = matches.get_many::<String>("environment") {
+        options = options.environments(environments);
+    } else if let Some(environments) = &config.environments {
+        options = options.environments(environments);
+    }
+    if let Some(output) = output_dir(matches, config) {
+        options = options.output_dir(output);
+    }
+    if let Some(max) = matches
+        .get_one::<usize>("max_path_len")
+        .or(config.max_path_len.as_ref())
+    {
+        options = options.max_path_len(*max);
+    }
+    if let Some(email) = pick(matches, "security_email", config.security_email.as_ref()) {
+        options = options.security_email(email);
+    }
+    options = options.init_git(flag("git", config.git));
+    if let Some(message) = pick(matches, "git_message", config.git_message.as_ref()) {
+        options = options.git_message(message);
+    }
+    if let Some(python) = pick(matches, "python", config.python.as_ref()) {
+        options = options.python_version(python);
+    }
+    if let Some(template_dir) = matches.get_one::<PathBuf>("template_dir") {
+        options = options.template_dir(template_dir);
+    }
+    if let Some(overrides_dir) = matches.get_one::<PathBuf>("template_overrides_dir") {
+        options = options.template_overrides_dir(overrides_dir);
+    }
+    Ok(options)
+}
+
+/// Prints `question` and reads one answer from `input`, trimmed. An empty
+/// answer gives `default`, and `None` means the input was closed.
+fn ask(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+) -> io::Result<Option<String>> {
+    if default.is_empty() {
+        write!(output, "{question}: ")?;
+    } else {
+        write!(output, "{question} [{default}]: ")?;
+    }
+    output.flush()?;
+    let mut answer = String::new();
+    if input.read_line(&mut answer)? == 0 {
+        return Ok(None);
+    }
+    let answer = answer.trim();
+    Ok(Some(
+        if answer.is_empty() { default } else { answer }.to_string(),
+    ))
+}
+
+/// Asks until `check` accepts the answer, printing its error each time.
+fn ask_until<T>(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: &str,
+    check: impl Fn(&str) -> Result<T, String>,
+) -> io::Result<Option<T>> {
+    while let Some(answer) = ask(input, output, question, default)? {
+        match check(&answer) {
+            Ok(value) => return Ok(Some(value)),
+            Err(error) => writeln!(output, "{error}")?,
+        }
+    }
+    Ok(None)
+}
+
+/// Asks for one of `choices`, the first one being the default.
+fn ask_choice(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    choices: &[&str],
+) -> io::Result<Option<String>> {
+    let question = format!("{question} ({})", choices.join("/"));
+    ask_until(input, output, &question, choices[0], |answer| {
+        let answer = answer.to_lowercase();
+        match choices.contains(&answer.as_str()) {
+            true => Ok(answer),
+            false => Err(format!("Pick one of: {}", choices.join(", "))),
+        }
+    })
+}
+
+/// Walks a newcomer through the main options of `new`. The answers come back
+/// as a [`SkeletonConfig`], so flags given next to `--interactive` still win.
+/// Names are checked like [`SkeletonOptions::build`] does, asking again on an
+/// error. Returns `None` when `input` is closed before the end.
+fn wizard(input: &mut impl BufRead, output: &mut impl Write) -> io::Result<Option<SkeletonConfig>> {
+    let Some(project) = ask_until(input, output, "Project name (Train-Case)", "", |name| {
+        check_name(name, Case::TrainCase).map_err(|error| error.to_string())
+    })?
+    else {
+        return Ok(None);
+    };
+    // The project name is valid, its snake_case form is a good default.
+    let default_package =
+        convert_case(&project, Case::TrainCase, Case::SnakeCase).unwrap_or_default();
+    let question = "Package name (snake_case)";
+    let Some(package) = ask_until(input, output, question, &default_package, |name| {
+        let segments = name
+            .split('.')
+            .map(|segment| check_name(segment, Case::SnakeCase))
+            .collect::<Result<Vec<_>, _>>();
+        segments
+            .map(|segments| segments.join("."))
+            .map_err(|error| error.to_string())
+    })?
+    else {
+        return Ok(None);
+    };
+    let Some(docs) = ask_choice(input, output, "Add a docs folder?", &["n", "y"])? else {
+        return Ok(None);
+    };
+    let databases = ["none", "oracle", "postgres", "mysql", "sqlite"];
+    let Some(database) = ask_choice(input, output, "Database", &databases)? else {
+        return Ok(None);
+    };
+    let licenses = ["none", "mit", "apache-2.0", "bsd-3-clause", "gpl-3.0"];
+    let Some(license) = ask_choice(input, output, "License", &licenses)? else {
+        return Ok(None);
+    };
+    Ok(Some(SkeletonConfig {
+        project: Some(project),
+        package: Some(package),
+        docs: Some(docs == "y"),
+        database: Some(database),
+        license: Some(license),
+        ..SkeletonConfig::default()
+    }))
+}
+
+/// Prints the paths of `report` relative to `base` like the `tree` command, with
+/// branch characters joining every entry to its parent and a trailing `/` on
+/// directories.
+fn print_tree(output: &mut impl Write, base: &Path, report: &BuildReport) -> io::Result<()> {
+    let mut entries: Vec<(&Path, bool)> = report
+        .created_dirs
+        .iter()
+        .map(|dir| (dir.strip_prefix(base).unwrap_or(dir), true))
+        .chain(
+            report
+                .created_files
+                .iter()
+                .map(|file| (file.strip_prefix(base).unwrap_or(file), false)),
+        )
+        .collect();
+    // Sorting by components puts every entry right below its parent.
+    entries.sort_by(|(a, _), (b, _)| a.components().cmp(b.components()));
+    // Whether the ancestor at each depth has siblings still to come, which keeps
+    // its `│` going down.
+    let mut open: Vec<bool> = Vec::new();
+    for (index, &(path, is_dir)) in entries.iter().enumerate() {
+        let depth = path.components().count().saturating_sub(1);
+        let last = !entries[index + 1..]
+            .iter()
+            .any(|(other, _)| other.parent() == path.parent());
+        // Parents that already existed are not listed, nothing goes down from them.
+        open.resize(depth, false);
+        let mut line = String::new();
+        if depth > 0 {
+            for &more in open.iter().skip(1) {
+                line.push_str(if more { "│   " } else { "    " });
+            }
+            line.push_str(if last { "└── " } else { "├── " });
+        }
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let slash = if is_dir { "/" } else { "" };
+        writeln!(output, "{line}{name}{slash}")?;
+        open.push(!last);
+    }
+    Ok(())
+}
+
+fn new(matches: &ArgMatches) -> ExitCode {
+    let config = if matches.get_flag("interactive") {
+        match wizard(&mut io::stdin().lock(), &mut io::stdout()) {
+            Ok(Some(config)) => Ok(config),
+            Ok(None) => {
+                eprintln!("\nAborted, nothing was built.");
+                return ExitCode::FAILURE;
+            }
+            Err(error) => Err(error.to_string()),
+        }
+    } else {
+        SkeletonConfig::load(matches)
+    };
+    let json = matches.get_one::<String>("format").unwrap() == "json";
+    // Tools reading the JSON get their errors on stdout too.
+    let fail = |message: String, code: ExitCode| {
+        if json {
+            println!("{}", json!({ "status": "error", "error": message }));
+        } else {
+            eprintln!("{message}");
+        }
+        code
+    };
+    let config = match config {
+        Ok(config) => config,
+        Err(error) => return fail(error, ExitCode::FAILURE),
+    };
+    let options = match new_options(matches, &config) {
+        Ok(options) => options,
+        Err(error) => return fail(error, ExitCode::FAILURE),
+    };
+    let dry_run = matches.get_flag("dry_run");
+    let base = match output_dir(matches, &config) {
+        Some(output) => output,
+        None => current_dir().unwrap_or_default(),
+    };
+    // The tree of a dry run and the JSON already list every path. The paths are
+    // never logged, so the `debug` logs of `-v` only add the validation steps.
+    let verbose = matches.get_flag("verbose") && !dry_run && !json;
+    let result = if verbose {
+        options.build_with_progress(&mut io::stdout())
+    } else {
+        options.build()
+    };
     match result {
-        Ok(_) => println!("Ypur project is ready to work!"),
-        Err(_) => println!("Ops, check your inputs and try again."),
+        Ok(report) if json => {
+            let mut output = serde_json::to_value(&report).unwrap();
+            output["status"] = json!("ok");
+            output["dry_run"] = json!(dry_run);
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Ok(report) if dry_run => {
+            let _ = print_tree(&mut io::stdout(), &base, &report);
+            ExitCode::SUCCESS
+        }
+        Ok(_) if matches.get_flag("quiet") => ExitCode::SUCCESS,
+        Ok(report) => {
+            for warning in &report.warnings {
+                eprintln!("Warning: {warning}");
+            }
+            // The progress lines of a verbose build already list every path.
+            if !verbose {
+                let _ = print_tree(&mut io::stdout(), &base, &report);
+            }
+            println!("Ypur project is ready to work!");
+            ExitCode::SUCCESS
+        }
+        Err(error @ (BuildError::AlreadyExists(_) | BuildError::NotEmpty(_))) => fail(
+            format!("{error}. Use --force to build over it."),
+            ExitCode::FAILURE,
+        ),
+        Err(error) => fail(format!("{error}."), exit_code(&error)),
+    }
+}
+
+/// Invalid names exit with 2, so scripts can tell them from filesystem and
+/// other failures, which exit with 1.
+fn exit_code(error: &BuildError) -> ExitCode {
+    match error {
+        BuildError::NameError => ExitCode::from(2),
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn validate(matches: &ArgMatches) -> ExitCode {
+    let case = match matches.get_one::<String>("case").unwrap().as_str() {
+        "screaming-snake" => Case::ScreamingSnakeCase,
+        "kebab" => Case::KebabCase,
+        "train" => Case::TrainCase,
+        "pascal" => Case::PascalCase,
+        "camel" => Case::CamelCase,
+        _ => Case::SnakeCase,
+    };
+    let name = matches.get_one::<String>("name").unwrap();
+    match validate_name_collect(name, case, &ValidationOptions::default()) {
+        Ok(name) => {
+            println!("{name}");
+            ExitCode::SUCCESS
+        }
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{error}");
+            }
+            exit_code(&BuildError::NameError)
+        }
+    }
+}
+
+/// Prints the paths of a dry run with every option on, relative to the output
+/// directory. `force` keeps an existing `Project-Name` from stopping the plan.
+fn list_templates() -> ExitCode {
+    let Ok(dir) = current_dir() else {
+        eprintln!("Can not access the current directory.");
+        return ExitCode::FAILURE;
+    };
+    let plan = SkeletonOptions::new("Project-Name", "package_name")
+        .docs(true)
+        .ci(true)
+        .docker(true)
+        .precommit(true)
+        .editorconfig(true)
+        .community_files(true)
+        .changelog(true)
+        .security(true)
+        .makefile(true)
+        .conftest(true)
+        .python_version_file(true)
+        .version_file(true)
+        .requirements_txt(true)
+        .typing(true)
+        .logging(true)
+        .database(Database::Sqlite)
+        .test_runner(TestRunner::Tox)
+        .packaging(PackagingStyle::Legacy)
+        .license(License::Mit)
+        .output_dir(&dir)
+        .force(true)
+        .dry_run(true)
+        .build();
+    match plan {
+        Ok(report) => {
+            for path in report.created_dirs.iter().chain(&report.created_files) {
+                println!("{}", path.strip_prefix(&dir).unwrap_or(path).display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes the completion script of `shell` for the whole [`cmd`] to `output`.
+fn completions(shell: Shell, output: &mut impl Write) {
+    let mut cmd = cmd();
+    let name = cmd.get_name().to_string();
+    generate(shell, &mut cmd, name, output);
+}
+
+fn main() -> ExitCode {
+    let matches = cmd().get_matches();
+    // `RUST_LOG` still wins over the flag, for finer control when debugging.
+    let level = if matches.get_flag("verbose") {
+        LevelFilter::Debug
+    } else if matches.get_flag("quiet") {
+        LevelFilter::Error
+    } else {
+        LevelFilter::Warn
     };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .init();
+    match matches.subcommand() {
+        Some(("new", matches)) => new(matches),
+        Some(("validate", matches)) => validate(matches),
+        Some(("list-templates", _)) => list_templates(),
+        Some(("completions", matches)) => {
+            completions(*matches.get_one("shell").unwrap(), &mut io::stdout());
+            ExitCode::SUCCESS
+        }
+        // Without any argument, newcomers get the wizard of `new`.
+        _ => new(&new_cmd().get_matches_from(["new", "--interactive"])),
+    }
 }
 
 #[test]
 fn verify_app() {
     cmd().debug_assert();
 }
+
+#[test]
+fn branched_tree() {
+    let base = Path::new("/base");
+    let report = BuildReport {
+        created_dirs: vec![
+            base.join("Root"),
+            base.join("Root/src"),
+            base.join("Root/src/pkg"),
+            base.join("Root/test"),
+        ],
+        created_files: vec![
+            base.join("Root/README.md"),
+            base.join("Root/src/pkg/__init__.py"),
+            base.join("Root/src/pkg/main.py"),
+            base.join("Root/test/sample_test.py"),
+        ],
+        ..BuildReport::default()
+    };
+    let mut output = Vec::new();
+    print_tree(&mut output, base, &report).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Root/
+├── README.md
+├── src/
+│   └── pkg/
+│       ├── __init__.py
+│       └── main.py
+└── test/
+    └── sample_test.py
+"
+    );
+}
+
+#[test]
+fn parse_subcommands() {
+    let matches = cmd()
+        .try_get_matches_from(["python-skeleton", "validate", "my-name", "--case", "kebab"])
+        .unwrap();
+    let (name, validate) = matches.subcommand().unwrap();
+    assert_eq!(name, "validate");
+    assert_eq!(validate.get_one::<String>("case").unwrap(), "kebab");
+
+    assert!(
+        cmd()
+            .try_get_matches_from(["python-skeleton", "My-Project", "my_package"])
+            .is_err()
+    );
+    // The package name is optional, it is derived when building.
+    let matches = cmd()
+        .try_get_matches_from(["python-skeleton", "new", "My-Cool-Lib"])
+        .unwrap();
+    let new = matches.subcommand_matches("new").unwrap();
+    let options = new_options(new, &SkeletonConfig::default()).unwrap();
+    let plan = options.plan().unwrap();
+    assert!(plan.dirs.iter().any(|dir| dir.ends_with("src/my_cool_lib")));
+}
+
+#[test]
+fn config_file_defaults() {
+    let config: SkeletonConfig = toml::from_str(
+        r#"
+        project = "Config-Project"
+        package = "config_project"
+        license = "mit"
+        layout = "flat"
+        backend = "bogus"
+        ci = true
+        "#,
+    )
+    .unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().to_str().unwrap();
+    let matches = |args: &[&str]| {
+        let mut argv = vec!["python-skeleton", "new", "--config", "skeleton.toml"];
+        argv.extend(["--dry-run", "-o", output]);
+        argv.extend(args);
+        let matches = cmd().try_get_matches_from(argv).unwrap();
+        matches.subcommand_matches("new").unwrap().clone()
+    };
+
+    // An invalid value in the file is reported, unless a flag replaces it.
+    assert_eq!(
+        new_options(&matches(&[]), &config).unwrap_err(),
+        "Unknown backend `bogus`"
+    );
+    let report = new_options(
+        &matches(&["--backend", "pdm", "--license", "none"]),
+        &config,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+    let root = dir.path().join("Config-Project");
+    assert!(
+        report
+            .created_files
+            .contains(&root.join("config_project/main.py"))
+    );
+    assert!(
+        report
+            .created_files
+            .contains(&root.join(".github/workflows/ci.yml"))
+    );
+    assert!(!report.created_files.contains(&root.join("LICENSE")));
+}
+
+#[test]
+fn wizard_asks_again_and_stops_on_eof() {
+    let answers = "my project\nMy-Project\nacme.cli-ent\nacme.client\ny\nredis\nsqlite\n\n";
+    let mut output = Vec::new();
+    let config = wizard(&mut answers.as_bytes(), &mut output)
+        .unwrap()
+        .unwrap();
+    assert_eq!(config.project.as_deref(), Some("My-Project"));
+    assert_eq!(config.package.as_deref(), Some("acme.client"));
+    assert_eq!(config.docs, Some(true));
+    assert_eq!(config.database.as_deref(), Some("sqlite"));
+    assert_eq!(config.license.as_deref(), Some("none"));
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches("Project name").count(), 2);
+    assert_eq!(output.matches("Package name").count(), 2);
+    assert!(output.contains("Pick one of: none, oracle"));
+
+    let config = wizard(&mut "My-Project\n".as_bytes(), &mut Vec::new()).unwrap();
+    assert_eq!(config, None);
+}
+
+#[test]
+fn bash_completions() {
+    let mut output = Vec::new();
+    completions(Shell::Bash, &mut output);
+    let script = String::from_utf8(output).unwrap();
+    assert!(script.contains("python-skeleton"));
+    assert!(script.contains("list-templates"));
+}
@@ -1,29 +1,68 @@
 //! Utilities for validating and normalizing identifier-like names in specific
 //! casing styles.
 //!
-//! This module supports two cases:
+//! This module supports the following cases:
 //!
 //! - **SnakeCase**: lower-case letters with underscores (`_`).
+//! - **ScreamingSnakeCase**: upper-case letters with underscores (`_`)
+//!   (e.g., `SK_LEARN`).
 //! - **TrainCase**: hyphen-separated words with each word starting in upper-case
 //!   (e.g., `Sk-Learn`).
+//! - **KebabCase**: lower-case letters with hyphens (`-`) (e.g., `sk-learn`).
+//! - **PascalCase**: alphabetic words joined without separators, each starting
+//!   in upper-case (e.g., `SkLearn`).
+//! - **CamelCase**: like PascalCase, but the first word starts in lower-case
+//!   (e.g., `skLearn`).
 //!
 //! The core entry point is [`check_name`], which validates an input string against
 //! the requested [`Case`] and, if valid (or fixable), returns a normalized form.
+//! When a name is rejected, [`slugify`] can coerce it into a conforming suggestion,
+//! and [`detect_case`] tells which case a name is already written in.
+//! [`check_name`] stops at the first problem; [`validate_name_collect`] reports
+//! all of them.
 //!
 //! # Rules
 //!
+//! - **Empty names are not allowed**; an empty or whitespace-only input yields
+//!   [`ErrorCase::EmptyName`].
+//! - **Only ASCII letters are allowed** by default; a Unicode letter such as the
+//!   `é` of `café` yields [`ErrorCase::SpecialCharNotAllowed`]. Set
+//!   [`ValidationOptions::unicode`] to accept them.
 //! - **Numbers are not allowed** in any case; encountering a digit yields
-//!   [`ErrorCase::NumberNotAllowed`].
+//!   [`ErrorCase::NumberNotAllowed`]. Use [`check_name_with`] and
+//!   [`ValidationOptions`] to accept them.
 //! - **Special characters** are restricted by case:
-//!   - For [`Case::SnakeCase`], only alphabetic ASCII letters and `_` are allowed.
-//!   - For [`Case::TrainCase`], only alphabetic ASCII letters and `-` are allowed.
+//!   - For [`Case::SnakeCase`] and [`Case::ScreamingSnakeCase`], only alphabetic
+//!     ASCII letters and `_` are allowed.
+//!   - For [`Case::TrainCase`] and [`Case::KebabCase`], only alphabetic ASCII
+//!     letters and `-` are allowed.
+//!   - For [`Case::PascalCase`] and [`Case::CamelCase`], only alphabetic ASCII
+//!     letters are allowed.
 //!   - Any other character yields [`ErrorCase::SpecialCharNotAllowed`].
+//! - **Separators must join words**: for [`Case::TrainCase`] and
+//!   [`Case::KebabCase`], a leading, trailing or repeated `-` yields
+//!   [`ErrorCase::MalformedSeparator`]; the same applies to `_` for
+//!   [`Case::SnakeCase`] and [`Case::ScreamingSnakeCase`].
+//! - **Windows device names are reserved** in every case: `con`, `prn`, `aux`,
+//!   `nul`, `com0`-`com9` and `lpt0`-`lpt9`, in any casing, yield
+//!   [`ErrorCase::ReservedName`], since such a directory can not be checked out
+//!   on Windows.
 //!
 //! # Normalization
 //!
-//! - [`Case::SnakeCase`]: the output is fully lowercased.
+//! - [`Case::SnakeCase`] and [`Case::KebabCase`]: the output is fully lowercased.
+//! - [`Case::ScreamingSnakeCase`]: the output is fully uppercased.
 //! - [`Case::TrainCase`]: the input is lowercased first, then each segment
 //!   (delimited by `-`) is capitalized by making its first character uppercase.
+//!   With [`TrainCaseStyle::PreserveAcronyms`], runs of two or more uppercase
+//!   letters are not lowercased, so `my-API-client` becomes `My-API-Client`
+//!   instead of `My-Api-Client`.
+//!   Empty segments (`-a`, `a--b`, `a-`) are never normalized; they are rejected
+//!   as [`ErrorCase::MalformedSeparator`], so the output is always deterministic.
+//! - [`Case::PascalCase`]: the first character is uppercased and the rest of the
+//!   input is preserved, so existing word boundaries (`myHttpClient`) are kept.
+//! - [`Case::CamelCase`]: the first character is lowercased and the rest of the
+//!   input is preserved.
 //!
 //! # Examples
 //!
@@ -31,37 +70,38 @@
 //! use python_skeleton::validation::{check_name, Case, ErrorCase};
 //!
 //! // SnakeCase: valid as-is
-//! assert_eq!(check_name("sk_learn".into(), Case::SnakeCase).unwrap(), "sk_learn");
+//! assert_eq!(check_name("sk_learn", Case::SnakeCase).unwrap(), "sk_learn");
 //!
 ////! // SnakeCase: fixable by lowercasing
-//! assert_eq!(check_name("Sk_learn".into(), Case::SnakeCase).unwrap(), "sk_learn");
+//! assert_eq!(check_name("Sk_learn", Case::SnakeCase).unwrap(), "sk_learn");
 //!
 //! // SnakeCase: invalid (contains '-')
 //! assert_eq!(
-//!     check_name("sk-learn".into(), Case::SnakeCase).unwrap_err(),
-//!     ErrorCase::SpecialCharNotAllowed
+//!     check_name("sk-learn", Case::SnakeCase).unwrap_err(),
+//!     ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 }
 //! );
 //!
 //! // TrainCase: normalized to "Sk-Learn"
-//! assert_eq!(check_name("sk-learn".into(), Case::TrainCase).unwrap(), "Sk-Learn");
+//! assert_eq!(check_name("sk-learn", Case::TrainCase).unwrap(), "Sk-Learn");
 //!
 //! // TrainCase: invalid (contains '_')
 //! assert_eq!(
-//!     check_name("sk_learn".into(), Case::TrainCase).unwrap_err(),
-//!     ErrorCase::SpecialCharNotAllowed
+//!     check_name("sk_learn", Case::TrainCase).unwrap_err(),
+//!     ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
 //! );
 //!
 //! // Numbers are not allowed in either case
 //! assert_eq!(
-//!     check_name("sk_learn2".into(), Case::SnakeCase).unwrap_err(),
-//!     ErrorCase::NumberNotAllowed
+//!     check_name("sk_learn2", Case::SnakeCase).unwrap_err(),
+//!     ErrorCase::NumberNotAllowed { ch: '2', index: 8 }
 //! );
 //! assert_eq!(
-//!     check_name("sk-learn2".into(), Case::TrainCase).unwrap_err(),
-//!     ErrorCase::NumberNotAllowed
+//!     check_name("sk-learn2", Case::TrainCase).unwrap_err(),
+//!     ErrorCase::NumberNotAllowed { ch: '2', index: 8 }
 //! );
 //! ```
 
+use std::error::Error;
 use std::fmt;
 
 /// Specifies the target casing and character rules to validate and normalize
@@ -70,8 +110,14 @@ use std::fmt;
 /// # Variants
 ///
 /// - [`Case::SnakeCase`]: lower-case letters with underscores (`_`).
+/// - [`Case::ScreamingSnakeCase`]: upper-case letters with underscores (`_`).
 /// - [`Case::TrainCase`]: hyphen-separated words with each word starting
 ///   in upper-case (e.g., `Sk-Learn`).
+/// - [`Case::KebabCase`]: lower-case letters with hyphens (`-`).
+/// - [`Case::PascalCase`]: alphabetic words without separators, each starting
+///   in upper-case (e.g., `SkLearn`).
+/// - [`Case::CamelCase`]: alphabetic words without separators, the first one
+///   in lower-case (e.g., `skLearn`).
 ///
 /// See [`check_name`] for validation and normalization behavior.
 ///
@@ -81,27 +127,65 @@ use std::fmt;
 /// use python_skeleton::validation::{Case, check_name};
 ///
 /// assert_eq!(
-///     check_name("Sk_learn".into(), Case::SnakeCase).unwrap(),
+///     check_name("Sk_learn", Case::SnakeCase).unwrap(),
 ///     "sk_learn"
 /// );
 ///
 /// assert_eq!(
-///     check_name("sk-learn".into(), Case::TrainCase).unwrap(),
+///     check_name("sk-learn", Case::TrainCase).unwrap(),
 ///     "Sk-Learn"
 /// );
+///
+/// assert_eq!(
+///     check_name("My-Lib", Case::KebabCase).unwrap(),
+///     "my-lib"
+/// );
+///
+/// assert_eq!(
+///     check_name("myClass", Case::PascalCase).unwrap(),
+///     "MyClass"
+/// );
+///
+/// assert_eq!(
+///     check_name("FooBar", Case::CamelCase).unwrap(),
+///     "fooBar"
+/// );
+///
+/// assert_eq!(
+///     check_name("db_host", Case::ScreamingSnakeCase).unwrap(),
+///     "DB_HOST"
+/// );
 /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Case {
     SnakeCase,
+    ScreamingSnakeCase,
     TrainCase,
+    KebabCase,
+    PascalCase,
+    CamelCase,
 }
 
 /// Errors that can occur while validating a name for a given [`Case`].
 ///
 /// # Variants
 ///
+/// - [`ErrorCase::EmptyName`]: the input was empty or only whitespace.
 /// - [`ErrorCase::NumberNotAllowed`]: the input contained numeric digits.
 /// - [`ErrorCase::SpecialCharNotAllowed`]: the input contained disallowed
-///   special characters (anything other than `_` for SnakeCase or `-` for TrainCase).
+///   special characters (anything other than `_` for SnakeCase and
+///   ScreamingSnakeCase, `-` for TrainCase and KebabCase, or any separator at
+///   all for PascalCase and CamelCase).
+/// - [`ErrorCase::MalformedSeparator`]: a separator was leading, trailing or
+///   repeated (e.g., `-Foo`, `Foo-` or `Foo--Bar` in TrainCase and KebabCase,
+///   `_foo`, `foo_` or `foo__bar` in SnakeCase and ScreamingSnakeCase).
+/// - [`ErrorCase::ReservedName`]: the input is a Windows device name, such as
+///   `con` or `com1`, carrying the normalized name.
+///
+/// [`ErrorCase::NumberNotAllowed`], [`ErrorCase::SpecialCharNotAllowed`] and
+/// [`ErrorCase::MalformedSeparator`] carry the offending character and its byte
+/// offset in the input, so long names can be fixed without guessing; it is
+/// also the character index as long as the name is ASCII.
 ///
 /// # Examples
 ///
@@ -110,64 +194,344 @@ pub enum Case {
 ///
 /// // Digit causes NumberNotAllowed
 /// assert_eq!(
-///     check_name("model2".into(), Case::SnakeCase).unwrap_err(),
-///     ErrorCase::NumberNotAllowed
+///     check_name("model2", Case::SnakeCase).unwrap_err(),
+///     ErrorCase::NumberNotAllowed { ch: '2', index: 5 }
 /// );
 ///
 /// // Space causes SpecialCharNotAllowed
 /// assert_eq!(
-///     check_name("sk learn".into(), Case::TrainCase).unwrap_err(),
-///     ErrorCase::SpecialCharNotAllowed
+///     check_name("sk learn", Case::TrainCase).unwrap_err(),
+///     ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 }
 /// );
 /// ```
 #[derive(Debug, PartialEq)]
 pub enum ErrorCase {
-    NumberNotAllowed,
-    SpecialCharNotAllowed,
+    EmptyName,
+    NumberNotAllowed { ch: char, index: usize },
+    SpecialCharNotAllowed { ch: char, index: usize },
+    MalformedSeparator { ch: char, index: usize },
+    ReservedName(String),
 }
 
 impl fmt::Display for ErrorCase {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ErrorCase::NumberNotAllowed => write!(f, "Numbers are not allowed!"),
-            ErrorCase::SpecialCharNotAllowed => write!(f, "Only alphabetic characters are allowed"),
+            ErrorCase::EmptyName => write!(f, "The name can not be empty"),
+            ErrorCase::NumberNotAllowed { ch, index } => {
+                write!(f, "Number '{ch}' at byte offset {index} is not allowed")
+            }
+            ErrorCase::SpecialCharNotAllowed { ch, index } => {
+                write!(f, "Invalid character '{ch}' at byte offset {index}")
+            }
+            ErrorCase::MalformedSeparator { ch, index } => {
+                write!(f, "Misplaced separator '{ch}' at byte offset {index}")
+            }
+            ErrorCase::ReservedName(ref name) => {
+                write!(f, "The name '{name}' is reserved on Windows")
+            }
         }
     }
 }
 
-fn validate_name_snake(name: String) -> Result<String, ErrorCase> {
-    for c in name.chars() {
-        if c.is_numeric() {
-            return Err(ErrorCase::NumberNotAllowed);
+impl Error for ErrorCase {}
+
+/// Relaxations of the default validation rules, used by [`check_name_with`].
+///
+/// The [`Default`] value is the strict behavior of [`check_name`]: no digits
+/// at all, and only ASCII letters.
+///
+/// # Fields
+///
+/// - `allow_digits`: accept numeric digits inside the name (e.g., `project_v2`).
+/// - `allow_leading_digit`: also accept a digit as the first character. Only
+///   meaningful together with `allow_digits`; Python identifiers can not start
+///   with a digit, so keep it disabled for package names.
+/// - `unicode`: accept any Unicode letter (e.g., `café`) and digit, not only
+///   ASCII ones. Python allows them in identifiers, but such paths do not
+///   travel well between filesystems and tools, so it is off by default.
+/// - `train_case`: how [`Case::TrainCase`] treats uppercase letters, see
+///   [`TrainCaseStyle`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ValidationOptions {
+    pub allow_digits: bool,
+    pub allow_leading_digit: bool,
+    pub unicode: bool,
+    pub train_case: TrainCaseStyle,
+}
+
+/// How [`Case::TrainCase`] normalizes the letters after the first one of each
+/// segment.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TrainCaseStyle {
+    /// Lowercase them: `my-API-client` becomes `My-Api-Client`.
+    #[default]
+    Capitalize,
+    /// Keep runs of two or more uppercase letters, taken as acronyms:
+    /// `my-API-client` becomes `My-API-Client`. A lone uppercase letter is
+    /// still lowercased, so `McDonald` becomes `Mcdonald`.
+    PreserveAcronyms,
+}
+
+impl ValidationOptions {
+    fn allows_digit(&self, index: usize) -> bool {
+        self.allow_digits && (index > 0 || self.allow_leading_digit)
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        if self.unicode {
+            c.is_alphabetic()
+        } else {
+            c.is_ascii_alphabetic()
         }
-        if !c.is_alphabetic() & (c != '_') {
-            return Err(ErrorCase::SpecialCharNotAllowed);
+    }
+
+    fn is_digit(&self, c: char) -> bool {
+        if self.unicode {
+            c.is_numeric()
+        } else {
+            c.is_ascii_digit()
         }
     }
-    Ok(name.to_lowercase())
+
+    /// Pushes `c` uppercased onto `name`, `ß` becoming `SS` with `unicode`.
+    fn push_upper(&self, name: &mut String, c: char) {
+        if self.unicode {
+            name.extend(c.to_uppercase());
+        } else {
+            name.push(c.to_ascii_uppercase());
+        }
+    }
+
+    /// Pushes `c` lowercased onto `name`.
+    fn push_lower(&self, name: &mut String, c: char) {
+        if self.unicode {
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c.to_ascii_lowercase());
+        }
+    }
+}
+
+/// Where the validators report the problems of a name: [`check_name_with`]
+/// stops at the first one, [`validate_name_collect`] keeps scanning.
+struct Problems {
+    errors: Vec<ErrorCase>,
+    collect: bool,
+}
+
+impl Problems {
+    /// Records `error`, returning `None` when the scan must stop there.
+    fn report(&mut self, error: ErrorCase) -> Option<()> {
+        self.errors.push(error);
+        self.collect.then_some(())
+    }
 }
 
-fn validate_name_train(name: String) -> Result<String, ErrorCase> {
+/// Runs the validator of `case`, returning the normalized name unless a
+/// problem stopped it.
+fn validate_case(
+    name: &str,
+    case: Case,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    match case {
+        Case::SnakeCase => validate_name_snake(name, options, problems),
+        Case::ScreamingSnakeCase => validate_name_screaming_snake(name, options, problems),
+        Case::TrainCase => validate_name_train(name, options, problems),
+        Case::KebabCase => validate_name_kebab(name, options, problems),
+        Case::PascalCase => validate_name_pascal(name, options, problems),
+        Case::CamelCase => validate_name_camel(name, options, problems),
+    }
+}
+
+fn validate_name_snake(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let mut previous_separator = true;
+    for (index, c) in name.char_indices() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) & (c != '_') {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
+        }
+        // An underscore right after another one (or at the start) would make
+        // `_private` or `foo__bar` style names that collide with dunders.
+        if (c == '_') & previous_separator {
+            problems.report(ErrorCase::MalformedSeparator { ch: c, index })?;
+        }
+        previous_separator = c == '_';
+    }
+    if previous_separator {
+        problems.report(ErrorCase::MalformedSeparator {
+            ch: '_',
+            index: name.len() - 1,
+        })?;
+    }
+    Some(name.to_lowercase())
+}
+
+fn validate_name_screaming_snake(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let mut previous_separator = true;
+    for (index, c) in name.char_indices() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) & (c != '_') {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
+        }
+        // Environment names end up in file names such as `config/DEV.yaml`.
+        if (c == '_') & previous_separator {
+            problems.report(ErrorCase::MalformedSeparator { ch: c, index })?;
+        }
+        previous_separator = c == '_';
+    }
+    if previous_separator {
+        problems.report(ErrorCase::MalformedSeparator {
+            ch: '_',
+            index: name.len() - 1,
+        })?;
+    }
+    Some(name.to_uppercase())
+}
+
+fn validate_name_kebab(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let mut previous_separator = true;
+    for (index, c) in name.char_indices() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) & (c != '-') {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
+        }
+        if (c == '-') & previous_separator {
+            problems.report(ErrorCase::MalformedSeparator { ch: c, index })?;
+        }
+        previous_separator = c == '-';
+    }
+    if previous_separator {
+        problems.report(ErrorCase::MalformedSeparator {
+            ch: '-',
+            index: name.len() - 1,
+        })?;
+    }
+    Some(name.to_lowercase())
+}
+
+fn validate_name_pascal(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let mut new_name = String::new();
+    for (index, c) in name.char_indices() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
+        }
+        if index == 0 {
+            options.push_upper(&mut new_name, c);
+            continue;
+        }
+        new_name.push(c);
+    }
+    Some(new_name)
+}
+
+fn validate_name_camel(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let mut new_name = String::new();
+    for (index, c) in name.char_indices() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
+        }
+        if index == 0 {
+            options.push_lower(&mut new_name, c);
+            continue;
+        }
+        new_name.push(c);
+    }
+    Some(new_name)
+}
+
+fn validate_name_train(
+    name: &str,
+    options: &ValidationOptions,
+    problems: &mut Problems,
+) -> Option<String> {
+    let chars: Vec<char> = name.chars().collect();
     let mut upper_case = true;
     let mut new_name = String::new();
-    for c in name.to_lowercase().chars() {
-        if c.is_numeric() {
-            return Err(ErrorCase::NumberNotAllowed);
+    // Indices come from `name`: lowercasing may change the length of a letter.
+    for (position, (index, c)) in name.char_indices().enumerate() {
+        if options.is_digit(c) {
+            if !options.allows_digit(index) {
+                problems.report(ErrorCase::NumberNotAllowed { ch: c, index })?;
+            }
+        } else if !options.is_letter(c) & (c != '-') {
+            problems.report(ErrorCase::SpecialCharNotAllowed { ch: c, index })?;
         }
-        if !c.is_alphabetic() & (c != '-') {
-            return Err(ErrorCase::SpecialCharNotAllowed);
+        // A hyphen at the start of a segment is leading (`-Foo`) or doubled (`Foo--Bar`).
+        if (c == '-') & upper_case {
+            problems.report(ErrorCase::MalformedSeparator { ch: c, index })?;
         }
         if upper_case {
-            new_name.push(c.to_ascii_uppercase());
+            options.push_upper(&mut new_name, c);
             upper_case = false;
             continue;
         }
         if c == '-' {
             upper_case = true;
         }
-        new_name.push(c);
+        if options.train_case == TrainCaseStyle::PreserveAcronyms && in_acronym(&chars, position) {
+            new_name.push(c);
+        } else {
+            options.push_lower(&mut new_name, c);
+        }
     }
-    Ok(new_name)
+    // Still waiting for a segment after the loop means the name ends with `-`.
+    if upper_case {
+        problems.report(ErrorCase::MalformedSeparator {
+            ch: '-',
+            index: name.len() - 1,
+        })?;
+    }
+    Some(new_name)
+}
+
+/// Whether the letter at `position` belongs to a run of two or more uppercase
+/// letters.
+fn in_acronym(chars: &[char], position: usize) -> bool {
+    let upper = |position: Option<usize>| {
+        position
+            .and_then(|position| chars.get(position))
+            .is_some_and(|c| c.is_uppercase())
+    };
+    upper(Some(position)) && (upper(position.checked_sub(1)) || upper(Some(position + 1)))
 }
 
 /// Validates and normalizes `name` according to the requested [`Case`].
@@ -175,15 +539,28 @@ fn validate_name_train(name: String) -> Result<String, ErrorCase> {
 /// On success, returns a normalized string:
 /// - [`Case::SnakeCase`]: returns the lowercased input if it contains only
 ///   alphabetic characters and underscores (`_`).
+/// - [`Case::ScreamingSnakeCase`]: returns the uppercased input under the same
+///   character rules as [`Case::SnakeCase`].
 /// - [`Case::TrainCase`]: returns a title-cased, hyphen-separated form:
 ///   the input is first lowercased; then after each hyphen (`-`), the next
-///   alphabetic character is uppercased.
+///   alphabetic character is uppercased. Names with empty segments are rejected
+///   instead of being normalized.
+/// - [`Case::KebabCase`]: returns the lowercased input if it contains only
+///   alphabetic characters and hyphens (`-`).
+/// - [`Case::PascalCase`]: returns the input with its first character
+///   uppercased, preserving the casing of the rest of the name.
+/// - [`Case::CamelCase`]: returns the input with its first character
+///   lowercased, preserving the casing of the rest of the name.
 ///
 /// # Errors
 ///
+/// - Returns [`ErrorCase::EmptyName`] if `name` is empty or only whitespace.
 /// - Returns [`ErrorCase::NumberNotAllowed`] if `name` contains any numeric digits.
 /// - Returns [`ErrorCase::SpecialCharNotAllowed`] if `name` contains disallowed
 ///   characters for the selected [`Case`].
+/// - Returns [`ErrorCase::MalformedSeparator`] if a separator is leading,
+///   trailing or repeated.
+/// - Returns [`ErrorCase::ReservedName`] if `name` is a Windows device name.
 ///
 /// # Complexity
 ///
@@ -195,23 +572,387 @@ fn validate_name_train(name: String) -> Result<String, ErrorCase> {
 /// use python_skeleton::validation::{check_name, Case, ErrorCase};
 ///
 /// // SnakeCase normalization
-/// assert_eq!(check_name("Sk_learn".into(), Case::SnakeCase).unwrap(), "sk_learn");
+/// assert_eq!(check_name("Sk_learn", Case::SnakeCase).unwrap(), "sk_learn");
 /// assert_eq!(
-///     check_name("sk-learn".into(), Case::SnakeCase).unwrap_err(),
-///     ErrorCase::SpecialCharNotAllowed
+///     check_name("sk-learn", Case::SnakeCase).unwrap_err(),
+///     ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 }
 /// );
 ///
 /// // TrainCase normalization
-/// assert_eq!(check_name("sk-learn".into(), Case::TrainCase).unwrap(), "Sk-Learn");
+/// assert_eq!(check_name("sk-learn", Case::TrainCase).unwrap(), "Sk-Learn");
+/// assert_eq!(
+///     check_name("sk_learn", Case::TrainCase).unwrap_err(),
+///     ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
+/// );
+///
+/// // KebabCase normalization
+/// assert_eq!(check_name("My-Lib", Case::KebabCase).unwrap(), "my-lib");
+///
+/// // PascalCase normalization
+/// assert_eq!(check_name("myClass", Case::PascalCase).unwrap(), "MyClass");
+///
+/// // CamelCase normalization
+/// assert_eq!(check_name("FooBar", Case::CamelCase).unwrap(), "fooBar");
+///
+/// // ScreamingSnakeCase normalization
+/// assert_eq!(
+///     check_name("db_host", Case::ScreamingSnakeCase).unwrap(),
+///     "DB_HOST"
+/// );
+/// ```
+pub fn check_name(name: &str, case: Case) -> Result<String, ErrorCase> {
+    check_name_with(name, case, &ValidationOptions::default())
+}
+
+/// Validates every name of `names` with [`check_name`], returning one result per
+/// name in the same order, so every failure can be reported at once.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{check_names, Case, ErrorCase};
+///
+/// let results = check_names(&["Sk_learn", "", "numpy"], Case::SnakeCase);
+/// assert_eq!(results[0], Ok("sk_learn".to_string()));
+/// assert_eq!(results[1], Err(ErrorCase::EmptyName));
+/// assert_eq!(results[2], Ok("numpy".to_string()));
+/// ```
+pub fn check_names(names: &[&str], case: Case) -> Vec<Result<String, ErrorCase>> {
+    names.iter().map(|name| check_name(name, case)).collect()
+}
+
+/// Validates and normalizes `name` like [`check_name`], relaxing the rules
+/// according to `options`.
+///
+/// With the default [`ValidationOptions`] this behaves exactly as [`check_name`].
+///
+/// # Errors
+///
+/// Same as [`check_name`], except that digits only yield
+/// [`ErrorCase::NumberNotAllowed`] when `options` does not permit them at
+/// their position.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{
+///     check_name_with, Case, ErrorCase, TrainCaseStyle, ValidationOptions,
+/// };
+///
+/// let options = ValidationOptions {
+///     allow_digits: true,
+///     allow_leading_digit: false,
+///     unicode: false,
+///     train_case: TrainCaseStyle::Capitalize,
+/// };
+///
+/// assert_eq!(
+///     check_name_with("lib2to3", Case::SnakeCase, &options).unwrap(),
+///     "lib2to3"
+/// );
 /// assert_eq!(
-///     check_name("sk_learn".into(), Case::TrainCase).unwrap_err(),
-///     ErrorCase::SpecialCharNotAllowed
+///     check_name_with("2to3", Case::SnakeCase, &options).unwrap_err(),
+///     ErrorCase::NumberNotAllowed { ch: '2', index: 0 }
 /// );
 /// ```
-pub fn check_name(name: String, case: Case) -> Result<String, ErrorCase> {
+pub fn check_name_with(
+    name: &str,
+    case: Case,
+    options: &ValidationOptions,
+) -> Result<String, ErrorCase> {
+    if name.trim().is_empty() {
+        return Err(ErrorCase::EmptyName);
+    }
+    let mut problems = Problems {
+        errors: Vec::new(),
+        collect: false,
+    };
+    match validate_case(name, case, options, &mut problems) {
+        Some(name) => check_reserved(name),
+        None => Err(problems.errors.remove(0)),
+    }
+}
+
+/// Validates `name` like [`check_name_with`], but scans the whole name instead
+/// of stopping at the first problem, so every one can be fixed at once.
+///
+/// # Errors
+///
+/// Returns every [`ErrorCase`] found, in the order of their position in `name`.
+/// An empty name only yields [`ErrorCase::EmptyName`], and a
+/// [`ErrorCase::ReservedName`] is only reported for an otherwise valid name.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{validate_name_collect, Case, ErrorCase, ValidationOptions};
+///
+/// let errors =
+///     validate_name_collect("sk-learn2", Case::SnakeCase, &ValidationOptions::default())
+///         .unwrap_err();
+/// assert_eq!(
+///     errors,
+///     [
+///         ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 },
+///         ErrorCase::NumberNotAllowed { ch: '2', index: 8 },
+///     ]
+/// );
+/// ```
+pub fn validate_name_collect(
+    name: &str,
+    case: Case,
+    options: &ValidationOptions,
+) -> Result<String, Vec<ErrorCase>> {
+    if name.trim().is_empty() {
+        return Err(vec![ErrorCase::EmptyName]);
+    }
+    let mut problems = Problems {
+        errors: Vec::new(),
+        collect: true,
+    };
+    let name = validate_case(name, case, options, &mut problems).unwrap_or_default();
+    if !problems.errors.is_empty() {
+        // A lone separator is both leading and trailing.
+        problems.errors.dedup();
+        return Err(problems.errors);
+    }
+    check_reserved(name).map_err(|error| vec![error])
+}
+
+/// Rejects the device names of Windows, whatever their casing.
+fn check_reserved(name: String) -> Result<String, ErrorCase> {
+    let lower = name.to_ascii_lowercase();
+    let numbered = |prefix: &str| {
+        lower
+            .strip_prefix(prefix)
+            .is_some_and(|n| n.len() == 1 && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+    if matches!(lower.as_str(), "con" | "prn" | "aux" | "nul") || numbered("com") || numbered("lpt")
+    {
+        return Err(ErrorCase::ReservedName(name));
+    }
+    Ok(name)
+}
+
+/// Splits `input` into its words, dropping the letters `options` does not
+/// accept.
+fn split_words(input: &str, options: &ValidationOptions) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in input.chars() {
+        if c.is_alphabetic() && !options.is_letter(c) {
+            continue;
+        }
+        if !c.is_alphabetic() {
+            // Digits, separators and symbols all end the current word.
+            if !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            previous_lower = false;
+            continue;
+        }
+        // A lower-to-upper transition starts a new word (`myClass` -> `my`, `Class`).
+        if c.is_uppercase() & previous_lower {
+            words.push(word);
+            word = String::new();
+        }
+        previous_lower = c.is_lowercase();
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn join_words(words: &[String], case: Case) -> String {
     match case {
-        Case::SnakeCase => validate_name_snake(name),
-        Case::TrainCase => validate_name_train(name),
+        Case::SnakeCase => words.join("_").to_lowercase(),
+        Case::ScreamingSnakeCase => words.join("_").to_uppercase(),
+        Case::KebabCase => words.join("-").to_lowercase(),
+        Case::TrainCase => words
+            .iter()
+            .map(|w| capitalize(w))
+            .collect::<Vec<_>>()
+            .join("-"),
+        Case::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+        Case::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Coerces an arbitrary `input` into a name that conforms to `case`.
+///
+/// Every run of non-alphabetic characters (spaces, punctuation, separators and
+/// digits) is treated as a word boundary and dropped, a lower-to-upper
+/// transition also starts a new word, and the resulting words are joined and
+/// cased as `case` requires. It is meant to offer "did you mean" suggestions, so
+/// strict validation stays in [`check_name`].
+///
+/// Non-ASCII letters are dropped, as [`check_name`] rejects them, so `café`
+/// gives `caf`; use [`slugify_with`] and [`ValidationOptions::unicode`] to keep
+/// them.
+///
+/// A returned name always passes [`check_name`]. Returns `None` when there is
+/// no such name to suggest: `input` has no allowed letters at all, such as
+/// `123`, or its words make a reserved name, such as `con`.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{slugify, Case};
+///
+/// assert_eq!(
+///     slugify("My Cool Project! 2", Case::TrainCase).unwrap(),
+///     "My-Cool-Project"
+/// );
+/// assert_eq!(slugify("my--cool  lib", Case::SnakeCase).unwrap(), "my_cool_lib");
+/// assert_eq!(slugify("myHttpClient", Case::KebabCase).unwrap(), "my-http-client");
+/// assert_eq!(slugify("!!!", Case::SnakeCase), None);
+/// ```
+pub fn slugify(input: &str, case: Case) -> Option<String> {
+    slugify_with(input, case, &ValidationOptions::default())
+}
+
+/// Coerces `input` into a name that conforms to `case` like [`slugify`], keeping
+/// the letters `options` accepts. A returned name always passes
+/// [`check_name_with`] with the same `options`.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{slugify_with, Case, ValidationOptions};
+///
+/// let unicode = ValidationOptions {
+///     unicode: true,
+///     ..ValidationOptions::default()
+/// };
+/// assert_eq!(
+///     slugify_with("Café au lait", Case::SnakeCase, &unicode).unwrap(),
+///     "café_au_lait"
+/// );
+/// ```
+pub fn slugify_with(input: &str, case: Case, options: &ValidationOptions) -> Option<String> {
+    let slug = join_words(&split_words(input, options), case);
+    check_name_with(&slug, case, options).ok()
+}
+
+/// Validates `name` against the `from` case, like [`check_name`], and re-emits
+/// its words in the `to` case, e.g. to derive a package name from a project name.
+///
+/// Words are delimited by the separators of `from` and by lower-to-upper
+/// transitions, so `myHttpClient` has three words.
+///
+/// # Errors
+///
+/// Returns the [`ErrorCase`] of [`check_name`] when `name` is not valid for `from`.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{convert_case, Case};
+///
+/// assert_eq!(
+///     convert_case("My-Cool-Lib", Case::TrainCase, Case::SnakeCase).unwrap(),
+///     "my_cool_lib"
+/// );
+/// assert_eq!(
+///     convert_case("myHttpClient", Case::CamelCase, Case::KebabCase).unwrap(),
+///     "my-http-client"
+/// );
+/// ```
+pub fn convert_case(name: &str, from: Case, to: Case) -> Result<String, ErrorCase> {
+    let name = check_name(name, from)?;
+    Ok(join_words(
+        &split_words(&name, &ValidationOptions::default()),
+        to,
+    ))
+}
+
+/// Guesses the [`Case`] `name` is written in, exactly as given.
+///
+/// Unlike [`check_name`], nothing is normalized: `Sk-learn` is not Train-Case
+/// because `learn` is not capitalized. Returns `None` when `name` follows no case,
+/// mixes separators, contains digits or symbols, or is ambiguous. A single word
+/// without separators is ambiguous: `sklearn` could be snake_case, kebab-case or
+/// camelCase, `Sklearn` Train-Case or PascalCase, and `SKLEARN` SCREAMING_SNAKE_CASE
+/// or PascalCase.
+///
+/// # Examples
+///
+/// ```rust
+/// use python_skeleton::validation::{detect_case, Case};
+///
+/// assert_eq!(detect_case("sk_learn"), Some(Case::SnakeCase));
+/// assert_eq!(detect_case("Sk-Learn"), Some(Case::TrainCase));
+/// assert_eq!(detect_case("skLearn"), Some(Case::CamelCase));
+/// assert_eq!(detect_case("sklearn"), None);
+/// ```
+pub fn detect_case(name: &str) -> Option<Case> {
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphabetic() || c == '_' || c == '-')
+    {
+        return None;
+    }
+    let underscore = name.contains('_');
+    let hyphen = name.contains('-');
+    let separator = match (underscore, hyphen) {
+        (true, true) => return None,
+        (true, false) => '_',
+        (false, true) => '-',
+        (false, false) => {
+            // One or more words joined without separators.
+            let mut chars = name.chars();
+            let first = chars.next()?;
+            let rest = chars.as_str();
+            let has_upper = rest.chars().any(|c| c.is_ascii_uppercase());
+            let has_lower = name.chars().any(|c| c.is_ascii_lowercase());
+            return match (first.is_ascii_uppercase(), has_upper, has_lower) {
+                (true, true, true) => Some(Case::PascalCase),
+                (false, true, true) => Some(Case::CamelCase),
+                _ => None,
+            };
+        }
+    };
+    let words: Vec<&str> = name.split(separator).collect();
+    if words.iter().any(|word| word.is_empty()) {
+        return None;
+    }
+    let lower = |word: &&str| word.chars().all(|c| c.is_ascii_lowercase());
+    let upper = |word: &&str| word.chars().all(|c| c.is_ascii_uppercase());
+    let capitalized = |word: &&str| {
+        let (first, rest) = word.split_at(1);
+        upper(&first) && lower(&rest)
+    };
+    match separator {
+        '_' if words.iter().all(lower) => Some(Case::SnakeCase),
+        '_' if words.iter().all(upper) => Some(Case::ScreamingSnakeCase),
+        '-' if words.iter().all(lower) => Some(Case::KebabCase),
+        '-' if words.iter().all(capitalized) => Some(Case::TrainCase),
+        _ => None,
     }
 }
 
@@ -225,11 +966,11 @@ mod tests {
         let fixable_name = String::from("Sk_learn");
 
         assert_eq!(
-            check_name(valid_name, Case::SnakeCase).ok().unwrap(),
+            check_name(&valid_name, Case::SnakeCase).ok().unwrap(),
             "sk_learn"
         );
         assert_eq!(
-            check_name(fixable_name, Case::SnakeCase).ok().unwrap(),
+            check_name(&fixable_name, Case::SnakeCase).ok().unwrap(),
             "sk_learn"
         );
     }
@@ -241,22 +982,22 @@ mod tests {
         let invalid_name_number = String::from("sk_learn2");
 
         assert_eq!(
-            check_name(invalid_name_dash, Case::SnakeCase)
+            check_name(&invalid_name_dash, Case::SnakeCase)
                 .err()
                 .unwrap(),
-            ErrorCase::SpecialCharNotAllowed
+            ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 }
         );
         assert_eq!(
-            check_name(invalid_name_space, Case::SnakeCase)
+            check_name(&invalid_name_space, Case::SnakeCase)
                 .err()
                 .unwrap(),
-            ErrorCase::SpecialCharNotAllowed
+            ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 }
         );
         assert_eq!(
-            check_name(invalid_name_number, Case::SnakeCase)
+            check_name(&invalid_name_number, Case::SnakeCase)
                 .err()
                 .unwrap(),
-            ErrorCase::NumberNotAllowed
+            ErrorCase::NumberNotAllowed { ch: '2', index: 8 }
         );
     }
 
@@ -266,15 +1007,44 @@ mod tests {
         let fixable_name = String::from("sk-learn");
 
         assert_eq!(
-            check_name(valid_name, Case::TrainCase).ok().unwrap(),
+            check_name(&valid_name, Case::TrainCase).ok().unwrap(),
             "Sk-Learn"
         );
         assert_eq!(
-            check_name(fixable_name, Case::TrainCase).ok().unwrap(),
+            check_name(&fixable_name, Case::TrainCase).ok().unwrap(),
             "Sk-Learn"
         );
     }
 
+    #[test]
+    fn test_train_case_acronyms() {
+        assert_eq!(
+            check_name("my-API-client", Case::TrainCase).unwrap(),
+            "My-Api-Client"
+        );
+
+        let options = ValidationOptions {
+            train_case: TrainCaseStyle::PreserveAcronyms,
+            ..ValidationOptions::default()
+        };
+        for (name, expected) in [
+            ("my-API-client", "My-API-Client"),
+            ("My-API-Client", "My-API-Client"),
+            ("my-api-client", "My-Api-Client"),
+            ("HTTPServer-kit", "HTTPServer-Kit"),
+            ("mcDonald", "Mcdonald"),
+        ] {
+            assert_eq!(
+                check_name_with(name, Case::TrainCase, &options).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            check_name_with("my--API", Case::TrainCase, &options).unwrap_err(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 3 }
+        );
+    }
+
     #[test]
     fn test_invalid_name_train() {
         let invalid_name_dash = String::from("sk_learn");
@@ -282,22 +1052,586 @@ mod tests {
         let invalid_name_number = String::from("sk-learn2");
 
         assert_eq!(
-            check_name(invalid_name_dash, Case::TrainCase)
+            check_name(&invalid_name_dash, Case::TrainCase)
                 .err()
                 .unwrap(),
-            ErrorCase::SpecialCharNotAllowed
+            ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
         );
         assert_eq!(
-            check_name(invalid_name_space, Case::TrainCase)
+            check_name(&invalid_name_space, Case::TrainCase)
                 .err()
                 .unwrap(),
-            ErrorCase::SpecialCharNotAllowed
+            ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 }
         );
         assert_eq!(
-            check_name(invalid_name_number, Case::TrainCase)
+            check_name(&invalid_name_number, Case::TrainCase)
                 .err()
                 .unwrap(),
-            ErrorCase::NumberNotAllowed
+            ErrorCase::NumberNotAllowed { ch: '2', index: 8 }
+        );
+    }
+
+    #[test]
+    fn test_valid_name_kebab() {
+        let valid_name = String::from("my-lib");
+        let fixable_name = String::from("My-Lib");
+
+        assert_eq!(
+            check_name(&valid_name, Case::KebabCase).ok().unwrap(),
+            "my-lib"
+        );
+        assert_eq!(
+            check_name(&fixable_name, Case::KebabCase).ok().unwrap(),
+            "my-lib"
+        );
+    }
+
+    #[test]
+    fn test_invalid_name_kebab() {
+        let invalid_name_underscore = String::from("my_lib");
+        let invalid_name_space = String::from("my lib");
+        let invalid_name_number = String::from("my-lib2");
+
+        assert_eq!(
+            check_name(&invalid_name_underscore, Case::KebabCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_space, Case::KebabCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_number, Case::KebabCase)
+                .err()
+                .unwrap(),
+            ErrorCase::NumberNotAllowed { ch: '2', index: 6 }
+        );
+    }
+
+    #[test]
+    fn test_valid_name_pascal() {
+        let valid_name = String::from("MyHttpClient");
+        let fixable_name = String::from("myClass");
+
+        assert_eq!(
+            check_name(&valid_name, Case::PascalCase).ok().unwrap(),
+            "MyHttpClient"
+        );
+        assert_eq!(
+            check_name(&fixable_name, Case::PascalCase).ok().unwrap(),
+            "MyClass"
+        );
+    }
+
+    #[test]
+    fn test_invalid_name_pascal() {
+        let invalid_name_dash = String::from("My-Class");
+        let invalid_name_underscore = String::from("My_Class");
+        let invalid_name_number = String::from("MyClass2");
+
+        assert_eq!(
+            check_name(&invalid_name_dash, Case::PascalCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_underscore, Case::PascalCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_number, Case::PascalCase)
+                .err()
+                .unwrap(),
+            ErrorCase::NumberNotAllowed { ch: '2', index: 7 }
+        );
+    }
+
+    #[test]
+    fn test_valid_name_camel() {
+        let valid_name = String::from("fooBar");
+        let fixable_name = String::from("FooBar");
+
+        assert_eq!(
+            check_name(&valid_name, Case::CamelCase).ok().unwrap(),
+            "fooBar"
+        );
+        assert_eq!(
+            check_name(&fixable_name, Case::CamelCase).ok().unwrap(),
+            "fooBar"
+        );
+    }
+
+    #[test]
+    fn test_invalid_name_camel() {
+        let invalid_name_dash = String::from("foo-Bar");
+        let invalid_name_underscore = String::from("foo_Bar");
+        let invalid_name_number = String::from("fooBar2");
+
+        assert_eq!(
+            check_name(&invalid_name_dash, Case::CamelCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '-', index: 3 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_underscore, Case::CamelCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '_', index: 3 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_number, Case::CamelCase)
+                .err()
+                .unwrap(),
+            ErrorCase::NumberNotAllowed { ch: '2', index: 6 }
+        );
+    }
+
+    #[test]
+    fn test_valid_name_screaming_snake() {
+        let valid_name = String::from("DB_HOST");
+        let fixable_name = String::from("db_host");
+
+        assert_eq!(
+            check_name(&valid_name, Case::ScreamingSnakeCase)
+                .ok()
+                .unwrap(),
+            "DB_HOST"
+        );
+        assert_eq!(
+            check_name(&fixable_name, Case::ScreamingSnakeCase)
+                .ok()
+                .unwrap(),
+            "DB_HOST"
+        );
+    }
+
+    #[test]
+    fn test_invalid_name_screaming_snake() {
+        let invalid_name_dash = String::from("DB-HOST");
+        let invalid_name_number = String::from("DB_HOST2");
+
+        assert_eq!(
+            check_name(&invalid_name_dash, Case::ScreamingSnakeCase)
+                .err()
+                .unwrap(),
+            ErrorCase::SpecialCharNotAllowed { ch: '-', index: 2 }
+        );
+        assert_eq!(
+            check_name(&invalid_name_number, Case::ScreamingSnakeCase)
+                .err()
+                .unwrap(),
+            ErrorCase::NumberNotAllowed { ch: '2', index: 7 }
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = check_name("sk-learn", Case::SnakeCase).err().unwrap();
+
+        assert_eq!(error.to_string(), "Invalid character '-' at byte offset 2");
+    }
+
+    #[test]
+    fn test_empty_name() {
+        assert_eq!(
+            check_name("", Case::SnakeCase).err().unwrap(),
+            ErrorCase::EmptyName
+        );
+        assert_eq!(
+            check_name("   ", Case::TrainCase).err().unwrap(),
+            ErrorCase::EmptyName
+        );
+    }
+
+    #[test]
+    fn test_check_names() {
+        let results = check_names(
+            &["My-Lib", "my_lib", "lib2", "-lib", "nul", "other-lib"],
+            Case::TrainCase,
+        );
+
+        assert_eq!(
+            results,
+            vec![
+                Ok("My-Lib".to_string()),
+                Err(ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }),
+                Err(ErrorCase::NumberNotAllowed { ch: '2', index: 3 }),
+                Err(ErrorCase::MalformedSeparator { ch: '-', index: 0 }),
+                Err(ErrorCase::ReservedName("Nul".to_string())),
+                Ok("Other-Lib".to_string()),
+            ]
+        );
+        assert!(check_names(&[], Case::SnakeCase).is_empty());
+    }
+
+    #[test]
+    fn test_reserved_names() {
+        for (name, case) in [
+            ("con", Case::SnakeCase),
+            ("NUL", Case::SnakeCase),
+            ("Aux", Case::TrainCase),
+            ("prn", Case::KebabCase),
+            ("Con", Case::PascalCase),
+        ] {
+            assert!(matches!(
+                check_name(name, case),
+                Err(ErrorCase::ReservedName(_))
+            ));
+        }
+        let options = ValidationOptions {
+            allow_digits: true,
+            allow_leading_digit: false,
+            unicode: false,
+            train_case: TrainCaseStyle::Capitalize,
+        };
+        assert_eq!(
+            check_name_with("Com1", Case::TrainCase, &options).unwrap_err(),
+            ErrorCase::ReservedName("Com1".to_string())
+        );
+        assert_eq!(
+            check_name_with("LPT9", Case::ScreamingSnakeCase, &options).unwrap_err(),
+            ErrorCase::ReservedName("LPT9".to_string())
+        );
+
+        // Only the whole name is reserved.
+        assert_eq!(check_name("console", Case::SnakeCase).unwrap(), "console");
+        assert_eq!(check_name("con_file", Case::SnakeCase).unwrap(), "con_file");
+        assert_eq!(
+            check_name_with("com10", Case::SnakeCase, &options).unwrap(),
+            "com10"
+        );
+        assert_eq!(
+            ErrorCase::ReservedName("con".to_string()).to_string(),
+            "The name 'con' is reserved on Windows"
+        );
+    }
+
+    #[test]
+    fn test_validate_name_collect() {
+        let options = ValidationOptions::default();
+        assert_eq!(
+            validate_name_collect("my lib2", Case::SnakeCase, &options).unwrap_err(),
+            [
+                ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 },
+                ErrorCase::NumberNotAllowed { ch: '2', index: 6 },
+            ]
+        );
+        assert_eq!(
+            validate_name_collect("-my--lib-", Case::TrainCase, &options).unwrap_err(),
+            [
+                ErrorCase::MalformedSeparator { ch: '-', index: 0 },
+                ErrorCase::MalformedSeparator { ch: '-', index: 4 },
+                ErrorCase::MalformedSeparator { ch: '-', index: 8 },
+            ]
+        );
+        assert_eq!(
+            validate_name_collect("_", Case::SnakeCase, &options).unwrap_err(),
+            [ErrorCase::MalformedSeparator { ch: '_', index: 0 }]
+        );
+        assert_eq!(
+            validate_name_collect(" ", Case::SnakeCase, &options).unwrap_err(),
+            [ErrorCase::EmptyName]
+        );
+        assert_eq!(
+            validate_name_collect("Con", Case::TrainCase, &options).unwrap_err(),
+            [ErrorCase::ReservedName("Con".to_string())]
+        );
+        assert_eq!(
+            validate_name_collect("Sk_learn", Case::SnakeCase, &options).unwrap(),
+            "sk_learn"
+        );
+        // The first problem is the one of `check_name`.
+        assert_eq!(
+            check_name("my lib2", Case::SnakeCase).unwrap_err(),
+            ErrorCase::SpecialCharNotAllowed { ch: ' ', index: 2 }
+        );
+    }
+
+    #[test]
+    fn test_unicode_letters() {
+        assert_eq!(
+            check_name("café", Case::SnakeCase).unwrap_err(),
+            ErrorCase::SpecialCharNotAllowed { ch: 'é', index: 3 }
+        );
+        assert_eq!(
+            check_name("Café-Crème", Case::TrainCase).unwrap_err(),
+            ErrorCase::SpecialCharNotAllowed { ch: 'é', index: 3 }
+        );
+
+        let options = ValidationOptions {
+            unicode: true,
+            ..ValidationOptions::default()
+        };
+        assert_eq!(
+            check_name_with("Café", Case::SnakeCase, &options).unwrap(),
+            "café"
+        );
+        assert_eq!(
+            check_name_with("café-crème", Case::TrainCase, &options).unwrap(),
+            "Café-Crème"
+        );
+        assert_eq!(
+            check_name_with("être-ou", Case::TrainCase, &options).unwrap(),
+            "Être-Ou"
+        );
+        assert_eq!(
+            check_name_with("élan", Case::PascalCase, &options).unwrap(),
+            "Élan"
+        );
+        assert_eq!(
+            check_name_with("Élan", Case::CamelCase, &options).unwrap(),
+            "élan"
+        );
+        // `İ` is two bytes but lowercases to three, the index is the one of the input.
+        assert_eq!(
+            check_name_with("İa-b!", Case::TrainCase, &options).unwrap_err(),
+            ErrorCase::SpecialCharNotAllowed { ch: '!', index: 5 }
+        );
+    }
+
+    #[test]
+    fn test_digits_with_options() {
+        let options = ValidationOptions {
+            allow_digits: true,
+            allow_leading_digit: false,
+            unicode: false,
+            train_case: TrainCaseStyle::Capitalize,
+        };
+
+        assert_eq!(
+            check_name_with("project_v2", Case::SnakeCase, &options)
+                .ok()
+                .unwrap(),
+            "project_v2"
+        );
+        assert_eq!(
+            check_name_with("project-v2", Case::TrainCase, &options)
+                .ok()
+                .unwrap(),
+            "Project-V2"
+        );
+        assert_eq!(
+            check_name_with("2to3", Case::SnakeCase, &options)
+                .err()
+                .unwrap(),
+            ErrorCase::NumberNotAllowed { ch: '2', index: 0 }
+        );
+
+        let options = ValidationOptions {
+            allow_digits: true,
+            allow_leading_digit: true,
+            unicode: false,
+            train_case: TrainCaseStyle::Capitalize,
+        };
+        assert_eq!(
+            check_name_with("2to3", Case::SnakeCase, &options)
+                .ok()
+                .unwrap(),
+            "2to3"
+        );
+    }
+
+    #[test]
+    fn test_slugify() {
+        let slug = |input, case| slugify(input, case).unwrap();
+        assert_eq!(
+            slug("My Cool Project! 2", Case::TrainCase),
+            "My-Cool-Project"
+        );
+        assert_eq!(slug("__My  Package__", Case::SnakeCase), "my_package");
+        assert_eq!(slug("db-host", Case::ScreamingSnakeCase), "DB_HOST");
+        assert_eq!(slug("my http client", Case::PascalCase), "MyHttpClient");
+        assert_eq!(slug("My-Http-Client", Case::CamelCase), "myHttpClient");
+    }
+
+    #[test]
+    fn test_slugify_output_is_valid() {
+        let slug = slugify("Some weird_Name 3000", Case::TrainCase).unwrap();
+
+        assert_eq!(check_name(&slug, Case::TrainCase).ok().unwrap(), slug);
+    }
+
+    #[test]
+    fn test_slugify_without_valid_name() {
+        for case in [Case::SnakeCase, Case::TrainCase, Case::KebabCase] {
+            assert_eq!(slugify("42!", case), None);
+            assert_eq!(slugify("123", case), None);
+            assert_eq!(slugify("!!!", case), None);
+            assert_eq!(slugify("con", case), None);
+            assert_eq!(slugify("  AUX!", case), None);
+        }
+    }
+
+    #[test]
+    fn test_slugify_non_ascii() {
+        let slug = slugify("café au lait", Case::SnakeCase).unwrap();
+        assert_eq!(slug, "caf_au_lait");
+        assert_eq!(check_name(&slug, Case::SnakeCase).ok().unwrap(), slug);
+
+        let unicode = ValidationOptions {
+            unicode: true,
+            ..ValidationOptions::default()
+        };
+        let slug = slugify_with("Zürich Straße", Case::TrainCase, &unicode).unwrap();
+        assert_eq!(slug, "Zürich-Straße");
+        assert_eq!(
+            check_name_with(&slug, Case::TrainCase, &unicode)
+                .ok()
+                .unwrap(),
+            slug
+        );
+    }
+
+    #[test]
+    fn test_malformed_separator_train() {
+        assert_eq!(
+            check_name("-Foo", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 0 }
+        );
+        assert_eq!(
+            check_name("Foo-", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 3 }
+        );
+        assert_eq!(
+            check_name("Foo--Bar", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 4 }
+        );
+        assert_eq!(
+            check_name("-Foo-", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_malformed_separator_snake() {
+        assert_eq!(
+            check_name("_foo", Case::SnakeCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '_', index: 0 }
+        );
+        assert_eq!(
+            check_name("foo_", Case::SnakeCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '_', index: 3 }
+        );
+        assert_eq!(
+            check_name("foo__bar", Case::SnakeCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '_', index: 4 }
+        );
+        assert_eq!(
+            check_name("foo_bar_baz", Case::SnakeCase).ok().unwrap(),
+            "foo_bar_baz"
+        );
+    }
+
+    #[test]
+    fn test_malformed_separator_screaming_snake() {
+        for (name, index) in [("_DEV", 0), ("DEV_", 3), ("A__B", 2)] {
+            assert_eq!(
+                check_name(name, Case::ScreamingSnakeCase).err().unwrap(),
+                ErrorCase::MalformedSeparator { ch: '_', index }
+            );
+        }
+        assert_eq!(
+            check_name("db_host", Case::ScreamingSnakeCase)
+                .ok()
+                .unwrap(),
+            "DB_HOST"
+        );
+    }
+
+    #[test]
+    fn test_malformed_separator_kebab() {
+        for (name, index) in [("-ab", 0), ("ab-", 2), ("a--b", 2)] {
+            assert_eq!(
+                check_name(name, Case::KebabCase).err().unwrap(),
+                ErrorCase::MalformedSeparator { ch: '-', index }
+            );
+        }
+        assert_eq!(
+            check_name("sk-learn", Case::KebabCase).ok().unwrap(),
+            "sk-learn"
+        );
+    }
+
+    #[test]
+    fn test_train_empty_segments_are_rejected() {
+        // Regression: `a--b` used to be normalized to `A--b`, leaving the
+        // segment after the empty one in lower-case.
+        assert_eq!(
+            check_name("a--b", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 2 }
+        );
+        assert_eq!(
+            check_name("-a", Case::TrainCase).err().unwrap(),
+            ErrorCase::MalformedSeparator { ch: '-', index: 0 }
+        );
+        assert_eq!(check_name("a-b", Case::TrainCase).ok().unwrap(), "A-B");
+    }
+
+    #[test]
+    fn test_detect_case() {
+        for (name, case) in [
+            ("sk_learn", Case::SnakeCase),
+            ("SK_LEARN", Case::ScreamingSnakeCase),
+            ("sk-learn", Case::KebabCase),
+            ("Sk-Learn", Case::TrainCase),
+            ("A-B", Case::TrainCase),
+            ("SkLearn", Case::PascalCase),
+            ("HTTPClient", Case::PascalCase),
+            ("skLearn", Case::CamelCase),
+        ] {
+            assert_eq!(detect_case(name), Some(case), "{name}");
+            assert_eq!(check_name(name, case).unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn test_detect_case_ambiguous_or_invalid() {
+        for name in [
+            "",
+            "sklearn",
+            "Sklearn",
+            "SKLEARN",
+            "Sk-learn",
+            "sk_Learn",
+            "Sk_Learn",
+            "sk-learn_x",
+            "_sk",
+            "sk__learn",
+            "sk-",
+            "sk learn",
+            "sk_learn2",
+            "ñandu_x",
+        ] {
+            assert_eq!(detect_case(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_convert_case() {
+        let snake = convert_case("My-Cool-Lib", Case::TrainCase, Case::SnakeCase).unwrap();
+        assert_eq!(snake, "my_cool_lib");
+        let train = convert_case(&snake, Case::SnakeCase, Case::TrainCase).unwrap();
+        assert_eq!(train, "My-Cool-Lib");
+        // The input is normalized for `from` first.
+        assert_eq!(
+            convert_case("my-cool-lib", Case::TrainCase, Case::ScreamingSnakeCase).unwrap(),
+            "MY_COOL_LIB"
+        );
+        assert_eq!(
+            convert_case("sk_learn", Case::SnakeCase, Case::PascalCase).unwrap(),
+            "SkLearn"
+        );
+        assert_eq!(
+            convert_case("My_Lib", Case::TrainCase, Case::SnakeCase).unwrap_err(),
+            ErrorCase::SpecialCharNotAllowed { ch: '_', index: 2 }
         );
     }
 }
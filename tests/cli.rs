@@ -0,0 +1,160 @@
+#![cfg(feature = "cli")]
+
+use std::fs::{create_dir, read_to_string, write};
+use std::process::Command;
+use tempfile::tempdir;
+
+fn skeleton() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_python-skeleton"))
+}
+
+#[test]
+fn dry_run_prints_the_tree_and_writes_nothing() {
+    let dir = tempdir().unwrap();
+    let output = skeleton()
+        .args(["new", "Dry-Project", "dry_project", "--dry-run"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("Dry-Project/\n"));
+    assert!(stdout.contains("\n├── pyproject.toml\n"));
+    assert!(stdout.contains("\n│   └── dry_project/\n"));
+    assert_eq!(dir.path().read_dir().unwrap().count(), 0);
+}
+
+#[test]
+fn output_dir_is_created() {
+    let dir = tempdir().unwrap();
+    let output = dir.path().join("projects/python");
+    let status = skeleton()
+        .args(["new", "Output-Project", "output_project", "-o"])
+        .arg(&output)
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+    assert!(output.join("Output-Project/pyproject.toml").is_file());
+    assert!(
+        output
+            .join("Output-Project/src/output_project/main.py")
+            .is_file()
+    );
+}
+
+#[test]
+fn existing_project_needs_force() {
+    let dir = tempdir().unwrap();
+    let root = dir.path().join("Forced-Project");
+    create_dir(&root).unwrap();
+    write(root.join("README.md"), "old readme").unwrap();
+    write(root.join("notes.txt"), "keep me").unwrap();
+
+    let output = skeleton()
+        .args(["new", "Forced-Project", "forced_project"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already exists"));
+    assert_eq!(
+        read_to_string(root.join("README.md")).unwrap(),
+        "old readme"
+    );
+
+    let status = skeleton()
+        .args(["new", "Forced-Project", "forced_project", "--force"])
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert!(
+        read_to_string(root.join("README.md"))
+            .unwrap()
+            .starts_with("# Forced-Project")
+    );
+    assert_eq!(read_to_string(root.join("notes.txt")).unwrap(), "keep me");
+}
+
+#[test]
+fn invalid_name_exits_with_two() {
+    let dir = tempdir().unwrap();
+    let output = skeleton()
+        .args(["new", "not valid", "pkg"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("The project or package name is not valid"));
+    assert!(output.stdout.is_empty());
+    assert_eq!(dir.path().read_dir().unwrap().count(), 0);
+}
+
+#[test]
+fn validate_lists_every_problem() {
+    let output = skeleton()
+        .args(["validate", "my lib2", "--case", "snake"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        stderr,
+        "Invalid character ' ' at byte offset 2\nNumber '2' at byte offset 6 is not allowed\n"
+    );
+}
+
+#[test]
+fn quiet_prints_nothing_on_success() {
+    let dir = tempdir().unwrap();
+    let output = skeleton()
+        .args(["new", "Quiet-Project", "quiet_project", "--quiet"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+    assert!(dir.path().join("Quiet-Project/pyproject.toml").is_file());
+
+    let output = skeleton()
+        .args(["-q", "-v", "new", "Quiet-Project", "quiet_project"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn json_format_reports_the_build() {
+    let dir = tempdir().unwrap();
+    let output = skeleton()
+        .args(["new", "Json-Project", "json_project", "--format", "json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(report["status"], "ok");
+    let files = report["created_files"].as_array().unwrap();
+    let pyproject = dir.path().join("Json-Project/pyproject.toml");
+    assert!(files.contains(&serde_json::Value::from(pyproject.to_str().unwrap())));
+    assert!(report["created_dirs"].is_array());
+
+    let output = skeleton()
+        .args(["new", "Json-Project", "json_project", "--format", "json"])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let error: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(error["status"], "error");
+    assert!(error["error"].as_str().unwrap().contains("already exists"));
+}